@@ -0,0 +1,441 @@
+// self
+use crate::{packing::Packing, prelude::*};
+
+const STANDARD: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+static STANDARD2DIGIT: [Option<u8>; 256] = char2digit(STANDARD);
+static HEX2DIGIT: [Option<u8>; 256] = char2digit(HEX);
+static CROCKFORD2DIGIT: [Option<u8>; 256] = crockford2digit();
+static CROCKFORD_STRICT2DIGIT: [Option<u8>; 256] = char2digit(CROCKFORD);
+
+const fn char2digit(alphabet: &[u8; 32]) -> [Option<u8>; 256] {
+	let mut table = [None; 256];
+	let mut i = 0;
+
+	while i < 32 {
+		table[alphabet[i] as usize] = Some(i as u8);
+
+		i += 1;
+	}
+
+	table
+}
+
+// Crockford's decoding is case-insensitive, and `I`, `L` and `O` are read as `1`, `1` and `0`.
+const fn crockford2digit() -> [Option<u8>; 256] {
+	let mut table = char2digit(CROCKFORD);
+	let mut i = 0;
+
+	while i < 32 {
+		table[CROCKFORD[i].to_ascii_lowercase() as usize] = Some(i as u8);
+
+		i += 1;
+	}
+
+	table[b'I' as usize] = Some(1);
+	table[b'i' as usize] = Some(1);
+	table[b'L' as usize] = Some(1);
+	table[b'l' as usize] = Some(1);
+	table[b'O' as usize] = Some(0);
+	table[b'o' as usize] = Some(0);
+
+	table
+}
+
+/// The Base32 variants, the alphabet and whether the output is padded with `=`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base32Variant {
+	/// The standard alphabet with padding, RFC 4648 section 6.
+	#[default]
+	Standard,
+	/// The standard alphabet without padding.
+	StandardUnpadded,
+	/// The extended hex alphabet with padding, RFC 4648 section 7.
+	Hex,
+	/// The extended hex alphabet without padding.
+	HexUnpadded,
+	/// Crockford's alphabet without padding, case-insensitive and reading `I`, `L` and `O` as `1`,
+	/// `1` and `0` on decoding.
+	Crockford,
+	/// Crockford's alphabet without padding, only accepting the uppercase characters it encodes to.
+	CrockfordStrict,
+}
+impl Base32Variant {
+	fn chars(self) -> &'static [u8; 32] {
+		match self {
+			Self::Standard | Self::StandardUnpadded => STANDARD,
+			Self::Hex | Self::HexUnpadded => HEX,
+			Self::Crockford | Self::CrockfordStrict => CROCKFORD,
+		}
+	}
+
+	fn table(self) -> &'static [Option<u8>; 256] {
+		match self {
+			Self::Standard | Self::StandardUnpadded => &STANDARD2DIGIT,
+			Self::Hex | Self::HexUnpadded => &HEX2DIGIT,
+			Self::Crockford => &CROCKFORD2DIGIT,
+			Self::CrockfordStrict => &CROCKFORD_STRICT2DIGIT,
+		}
+	}
+
+	fn padded(self) -> bool {
+		matches!(self, Self::Standard | Self::Hex)
+	}
+
+	pub(crate) fn packing(self) -> Packing {
+		Packing { bits: 5, chars: self.chars(), table: self.table(), padded: self.padded() }
+	}
+}
+
+/// Base32ify `Self`.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Base32ify;
+///
+/// // `[u8; N]`.
+/// assert_eq!(b"Love".base32ify(), "JRXXMZI=");
+/// // `&[u8]`.
+/// assert_eq!(b"Love".as_slice().base32ify_unpadded(), "JRXXMZI");
+/// // `Vec<u8>`.
+/// assert_eq!(b"Love".to_vec().base32ify_hex(), "9HNNCP8=");
+/// assert_eq!(vec![0xde, 0xad, 0xbe, 0xef].base32ify_crockford(), "VTPVXVR");
+/// ```
+pub trait Base32ify {
+	/// Base32ify `Self` with the standard alphabet and padding.
+	fn base32ify(&self) -> String;
+
+	/// Base32ify `Self` with the standard alphabet and without padding.
+	fn base32ify_unpadded(&self) -> String;
+
+	/// Base32ify `Self` with the extended hex alphabet and padding.
+	fn base32ify_hex(&self) -> String;
+
+	/// Base32ify `Self` with the extended hex alphabet and without padding.
+	fn base32ify_hex_unpadded(&self) -> String;
+
+	/// Base32ify `Self` with Crockford's alphabet.
+	fn base32ify_crockford(&self) -> String;
+}
+macro_rules! base32ify_bytes_fns {
+	() => {
+		fn base32ify(&self) -> String {
+			base32ify(self, Base32Variant::Standard)
+		}
+
+		fn base32ify_unpadded(&self) -> String {
+			base32ify(self, Base32Variant::StandardUnpadded)
+		}
+
+		fn base32ify_hex(&self) -> String {
+			base32ify(self, Base32Variant::Hex)
+		}
+
+		fn base32ify_hex_unpadded(&self) -> String {
+			base32ify(self, Base32Variant::HexUnpadded)
+		}
+
+		fn base32ify_crockford(&self) -> String {
+			base32ify(self, Base32Variant::Crockford)
+		}
+	};
+}
+impl<const N: usize> Base32ify for [u8; N] {
+	base32ify_bytes_fns! {}
+}
+impl Base32ify for [u8] {
+	base32ify_bytes_fns! {}
+}
+impl Base32ify for Vec<u8> {
+	base32ify_bytes_fns! {}
+}
+#[test]
+fn base32ify_should_work() {
+	// RFC 4648 section 10.
+	assert_eq!(b"".base32ify(), "");
+	assert_eq!(b"f".base32ify(), "MY======");
+	assert_eq!(b"fo".base32ify(), "MZXQ====");
+	assert_eq!(b"foo".base32ify(), "MZXW6===");
+	assert_eq!(b"foob".base32ify(), "MZXW6YQ=");
+	assert_eq!(b"fooba".base32ify(), "MZXW6YTB");
+	assert_eq!(b"foobar".base32ify(), "MZXW6YTBOI======");
+	assert_eq!(b"f".base32ify_hex(), "CO======");
+	assert_eq!(b"fo".base32ify_hex(), "CPNG====");
+	assert_eq!(b"foo".base32ify_hex(), "CPNMU===");
+	assert_eq!(b"foob".base32ify_hex(), "CPNMUOG=");
+	assert_eq!(b"fooba".base32ify_hex(), "CPNMUOJ1");
+	assert_eq!(b"foobar".base32ify_hex(), "CPNMUOJ1E8======");
+	assert_eq!(b"foobar".as_slice().base32ify_unpadded(), "MZXW6YTBOI");
+	assert_eq!(b"foobar".to_vec().base32ify_hex_unpadded(), "CPNMUOJ1E8");
+	assert_eq!(b"foobar".base32ify_crockford(), "CSQPYRK1E8");
+	assert_eq!([0x00, 0x11, 0x22].base32ify_crockford(), "008J4");
+	assert_eq!([0xff].base32ify_crockford(), "ZW");
+}
+
+/// Debase32ify the given Base32 to `Self`.
+///
+/// The decoding is strict, RFC 4648's alphabets are uppercase only. The invalid characters are
+/// reported with [`Error::InvalidCharacter`] or [`Error::InvalidByte`], missing, extra or misplaced
+/// `=` with [`Error::InvalidPadding`], and the non-canonical encodings, whose unused bits are not
+/// zero, with [`Error::InvalidTrailingBits`].
+///
+/// Crockford's decoding is case-insensitive and reads `I`, `L` and `O` as `1`, `1` and `0`, unless
+/// the strict one is used. The `-` separators are not accepted.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Debase32ify, Error, LengthUnit};
+///
+/// // `[u8; N]`.
+/// assert_eq!(<[u8; 4]>::debase32ify("JRXXMZI="), Ok(*b"Love"));
+/// assert_eq!(
+/// 	<[u8; 5]>::debase32ify("JRXXMZI="),
+/// 	Err(Error::MismatchedLength { expect: 5, actual: 4, unit: LengthUnit::Byte })
+/// );
+/// // `Vec<u8>`.
+/// assert_eq!(<Vec<u8>>::debase32ify_unpadded("JRXXMZI"), Ok(b"Love".to_vec()));
+/// assert_eq!(<Vec<u8>>::debase32ify_hex("9HNNCP8="), Ok(b"Love".to_vec()));
+/// assert_eq!(<Vec<u8>>::debase32ify_crockford("vtpvxvr"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(<Vec<u8>>::debase32ify_crockford("0o8J4"), Ok(vec![0x00, 0x11, 0x22]));
+/// // Strict.
+/// assert_eq!(<Vec<u8>>::debase32ify("JRXXMZI"), Err(Error::InvalidPadding { index: 7 }));
+/// assert_eq!(<Vec<u8>>::debase32ify("JRXXMZJ="), Err(Error::InvalidTrailingBits { index: 6 }));
+/// assert_eq!(
+/// 	<Vec<u8>>::debase32ify_crockford_strict("0O8J4"),
+/// 	Err(Error::InvalidCharacter { character: 'O', index: 1, char_index: 1 })
+/// );
+/// ```
+pub trait Debase32ify
+where
+	Self: Sized,
+{
+	/// Debase32ify `Self` from Base32 with the standard alphabet and padding.
+	fn debase32ify<B>(base32: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase32ify `Self` from Base32 with the standard alphabet and without padding.
+	fn debase32ify_unpadded<B>(base32: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase32ify `Self` from Base32 with the extended hex alphabet and padding.
+	fn debase32ify_hex<B>(base32: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase32ify `Self` from Base32 with the extended hex alphabet and without padding.
+	fn debase32ify_hex_unpadded<B>(base32: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase32ify `Self` from Base32 with Crockford's alphabet, ignoring the case and the
+	/// ambiguous characters.
+	fn debase32ify_crockford<B>(base32: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase32ify `Self` from Base32 with Crockford's alphabet, rejecting the lowercase and the
+	/// ambiguous characters.
+	fn debase32ify_crockford_strict<B>(base32: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+}
+macro_rules! debase32ify_fns {
+	($f:ident) => {
+		fn debase32ify<B>(base32: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base32.as_ref(), Base32Variant::Standard)
+		}
+
+		fn debase32ify_unpadded<B>(base32: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base32.as_ref(), Base32Variant::StandardUnpadded)
+		}
+
+		fn debase32ify_hex<B>(base32: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base32.as_ref(), Base32Variant::Hex)
+		}
+
+		fn debase32ify_hex_unpadded<B>(base32: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base32.as_ref(), Base32Variant::HexUnpadded)
+		}
+
+		fn debase32ify_crockford<B>(base32: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base32.as_ref(), Base32Variant::Crockford)
+		}
+
+		fn debase32ify_crockford_strict<B>(base32: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base32.as_ref(), Base32Variant::CrockfordStrict)
+		}
+	};
+}
+impl<const N: usize> Debase32ify for [u8; N] {
+	debase32ify_fns! { debase32ify_array }
+}
+impl Debase32ify for Vec<u8> {
+	debase32ify_fns! { debase32ify_vec }
+}
+#[test]
+fn debase32ify_should_work() {
+	assert_eq!(<Vec<u8>>::debase32ify(""), Ok(Vec::new()));
+	assert_eq!(<Vec<u8>>::debase32ify("MY======"), Ok(b"f".to_vec()));
+	assert_eq!(<Vec<u8>>::debase32ify("MZXQ===="), Ok(b"fo".to_vec()));
+	assert_eq!(<Vec<u8>>::debase32ify("MZXW6==="), Ok(b"foo".to_vec()));
+	assert_eq!(<Vec<u8>>::debase32ify("MZXW6YQ="), Ok(b"foob".to_vec()));
+	assert_eq!(<Vec<u8>>::debase32ify("MZXW6YTB"), Ok(b"fooba".to_vec()));
+	assert_eq!(<[u8; 6]>::debase32ify("MZXW6YTBOI======"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 6]>::debase32ify_unpadded("MZXW6YTBOI"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 6]>::debase32ify_hex("CPNMUOJ1E8======"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 4]>::debase32ify_hex_unpadded("CPNMUOG"), Ok(*b"foob"));
+	assert_eq!(<[u8; 6]>::debase32ify_crockford("CSQPYRK1E8"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 6]>::debase32ify_crockford("csqpyrkie8"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 6]>::debase32ify_crockford("CSQPYRKLE8"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 6]>::debase32ify_crockford_strict("CSQPYRK1E8"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 3]>::debase32ify_crockford("OO8j4"), Ok([0x00, 0x11, 0x22]));
+	assert_eq!(<[u8; 1]>::debase32ify_crockford("zw"), Ok([0xff]));
+
+	// Padding.
+	assert_eq!(<Vec<u8>>::debase32ify("MY====="), Err(Error::InvalidPadding { index: 7 }));
+	assert_eq!(<Vec<u8>>::debase32ify("M======="), Err(Error::InvalidPadding { index: 1 }));
+	assert_eq!(<Vec<u8>>::debase32ify("MZX====="), Err(Error::InvalidPadding { index: 3 }));
+	assert_eq!(<Vec<u8>>::debase32ify("========"), Err(Error::InvalidPadding { index: 0 }));
+	assert_eq!(<Vec<u8>>::debase32ify_unpadded("MZX"), Err(Error::InvalidPadding { index: 2 }));
+	assert_eq!(
+		<Vec<u8>>::debase32ify_crockford("CSQPYRK1E"),
+		Err(Error::InvalidPadding { index: 8 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify("MY=Y===="),
+		Err(Error::InvalidCharacter { character: '=', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify_unpadded("MY======"),
+		Err(Error::InvalidCharacter { character: '=', index: 2, char_index: 2 })
+	);
+	// Canonical.
+	assert_eq!(<Vec<u8>>::debase32ify("MZ======"), Err(Error::InvalidTrailingBits { index: 1 }));
+	assert_eq!(<Vec<u8>>::debase32ify("MZXW7==="), Err(Error::InvalidTrailingBits { index: 4 }));
+	assert_eq!(
+		<Vec<u8>>::debase32ify_crockford("ZX"),
+		Err(Error::InvalidTrailingBits { index: 1 })
+	);
+	// Characters.
+	assert_eq!(
+		<Vec<u8>>::debase32ify("mzxw6ytb"),
+		Err(Error::InvalidCharacter { character: 'm', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify_unpadded("MZXW6YT我"),
+		Err(Error::InvalidCharacter { character: '我', index: 7, char_index: 7 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify_hex(b"CPNMUOJ\xff"),
+		Err(Error::InvalidByte { byte: 0xff, index: 7 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify_hex("CPNMUOJW"),
+		Err(Error::InvalidCharacter { character: 'W', index: 7, char_index: 7 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify_crockford("CSQPYRKUE8"),
+		Err(Error::InvalidCharacter { character: 'U', index: 7, char_index: 7 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify_crockford_strict("csqpyrk1e8"),
+		Err(Error::InvalidCharacter { character: 'c', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase32ify_crockford_strict("CSQPYRKIE8"),
+		Err(Error::InvalidCharacter { character: 'I', index: 7, char_index: 7 })
+	);
+	assert_eq!(
+		<[u8; 4]>::debase32ify("MZXW6YTB"),
+		Err(Error::MismatchedLength { expect: 4, actual: 5, unit: LengthUnit::Byte })
+	);
+}
+
+/// Debase32ify Base32 into a mutable slice source.
+///
+/// The slice must be exactly as long as the decoded bytes.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Base32Variant, Error, LengthUnit};
+///
+/// let mut bytes = [0; 4];
+///
+/// assert_eq!(
+/// 	array_bytes::debase32ify_slice_mut("JRXXMZI=", &mut bytes, Base32Variant::Standard),
+/// 	Ok(b"Love".as_slice())
+/// );
+/// assert_eq!(
+/// 	array_bytes::debase32ify_slice_mut("JRXXMZI", &mut bytes, Base32Variant::Standard),
+/// 	Err(Error::InvalidPadding { index: 7 })
+/// );
+/// assert_eq!(
+/// 	array_bytes::debase32ify_slice_mut("CSQPY", &mut bytes, Base32Variant::Crockford),
+/// 	Err(Error::MismatchedLength { expect: 4, actual: 3, unit: LengthUnit::Byte })
+/// );
+/// ```
+pub fn debase32ify_slice_mut<B>(
+	base32: B,
+	slice_src: &mut [u8],
+	variant: Base32Variant,
+) -> Result<&[u8]>
+where
+	B: AsRef<[u8]>,
+{
+	variant.packing().decode_slice_mut(base32.as_ref(), slice_src)
+}
+#[test]
+fn debase32ify_slice_mut_should_work() {
+	let mut bytes = [0; 6];
+
+	assert_eq!(
+		debase32ify_slice_mut("MZXW6YTBOI======", &mut bytes, Base32Variant::Standard),
+		Ok(b"foobar".as_slice())
+	);
+	assert_eq!(
+		debase32ify_slice_mut("vtpvxvr", &mut bytes[..4], Base32Variant::Crockford),
+		Ok([0xde, 0xad, 0xbe, 0xef].as_slice())
+	);
+	assert_eq!(
+		debase32ify_slice_mut("CPNMUOG", &mut bytes, Base32Variant::HexUnpadded),
+		Err(Error::MismatchedLength { expect: 6, actual: 4, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		debase32ify_slice_mut("CPNMUOJ1E.", &mut bytes, Base32Variant::HexUnpadded),
+		Err(Error::InvalidCharacter { character: '.', index: 9, char_index: 9 })
+	);
+}
+
+fn base32ify(bytes: &[u8], variant: Base32Variant) -> String {
+	variant.packing().encode(bytes)
+}
+
+fn debase32ify_array<const N: usize>(base32: &[u8], variant: Base32Variant) -> Result<[u8; N]> {
+	variant.packing().decode_array(base32)
+}
+
+fn debase32ify_vec(base32: &[u8], variant: Base32Variant) -> Result<Vec<u8>> {
+	variant.packing().decode_vec(base32)
+}
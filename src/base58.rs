@@ -0,0 +1,371 @@
+// core
+use core::iter;
+// self
+use crate::{hash, prelude::*};
+
+const BITCOIN: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const RIPPLE: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+const FLICKR: &[u8; 58] = b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+
+static BITCOIN2DIGIT: [Option<u8>; 256] = char2digit(BITCOIN);
+static RIPPLE2DIGIT: [Option<u8>; 256] = char2digit(RIPPLE);
+static FLICKR2DIGIT: [Option<u8>; 256] = char2digit(FLICKR);
+
+const fn char2digit(alphabet: &[u8; 58]) -> [Option<u8>; 256] {
+	let mut table = [None; 256];
+	let mut i = 0;
+
+	while i < 58 {
+		table[alphabet[i] as usize] = Some(i as u8);
+
+		i += 1;
+	}
+
+	table
+}
+
+/// The Base58 alphabets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base58Alphabet {
+	/// Bitcoin's alphabet, also used by IPFS, Solana and Substrate.
+	#[default]
+	Bitcoin,
+	/// Ripple's alphabet.
+	Ripple,
+	/// Flickr's alphabet.
+	Flickr,
+}
+impl Base58Alphabet {
+	fn chars(self) -> &'static [u8; 58] {
+		match self {
+			Self::Bitcoin => BITCOIN,
+			Self::Ripple => RIPPLE,
+			Self::Flickr => FLICKR,
+		}
+	}
+
+	fn table(self) -> &'static [Option<u8>; 256] {
+		match self {
+			Self::Bitcoin => &BITCOIN2DIGIT,
+			Self::Ripple => &RIPPLE2DIGIT,
+			Self::Flickr => &FLICKR2DIGIT,
+		}
+	}
+}
+
+/// Base58ify `Self`.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Base58Alphabet, Base58ify};
+///
+/// // `[u8; N]`.
+/// assert_eq!(b"Hello World!".base58ify(), "2NEpo7TZRRrLZSi2U");
+/// // `&[u8]`.
+/// assert_eq!(
+/// 	b"Hello World!".as_slice().base58ify_with(Base58Alphabet::Flickr),
+/// 	"2nePN7syqqRkyrH2t"
+/// );
+/// // `Vec<u8>`.
+/// assert_eq!([0; 21].to_vec().base58ify_check(), "1111111111111111111114oLvT2");
+/// assert_eq!([0; 21].base58ify_check_with(Base58Alphabet::Ripple), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+/// ```
+pub trait Base58ify {
+	/// Base58ify `Self` with the Bitcoin alphabet.
+	fn base58ify(&self) -> String;
+
+	/// Base58ify `Self` with the given alphabet.
+	fn base58ify_with(&self, alphabet: Base58Alphabet) -> String;
+
+	/// Base58Check `Self` with the Bitcoin alphabet.
+	///
+	/// The first 4 bytes of the double SHA-256 of `Self` are appended as the checksum.
+	fn base58ify_check(&self) -> String;
+
+	/// Base58Check `Self` with the given alphabet.
+	fn base58ify_check_with(&self, alphabet: Base58Alphabet) -> String;
+}
+macro_rules! base58ify_bytes_fns {
+	() => {
+		fn base58ify(&self) -> String {
+			base58ify(self, Base58Alphabet::Bitcoin)
+		}
+
+		fn base58ify_with(&self, alphabet: Base58Alphabet) -> String {
+			base58ify(self, alphabet)
+		}
+
+		fn base58ify_check(&self) -> String {
+			base58ify_check(self, Base58Alphabet::Bitcoin)
+		}
+
+		fn base58ify_check_with(&self, alphabet: Base58Alphabet) -> String {
+			base58ify_check(self, alphabet)
+		}
+	};
+}
+impl<const N: usize> Base58ify for [u8; N] {
+	base58ify_bytes_fns! {}
+}
+impl Base58ify for [u8] {
+	base58ify_bytes_fns! {}
+}
+impl Base58ify for Vec<u8> {
+	base58ify_bytes_fns! {}
+}
+#[test]
+fn base58ify_should_work() {
+	assert_eq!(b"".base58ify(), "");
+	assert_eq!([0].base58ify(), "1");
+	assert_eq!([0, 0, 1].base58ify(), "112");
+	assert_eq!([0xff; 4].base58ify(), "7YXq9G");
+	assert_eq!(b"Hello World!".base58ify(), "2NEpo7TZRRrLZSi2U");
+	assert_eq!(
+		b"Hello World!".as_slice().base58ify_with(Base58Alphabet::Ripple),
+		"p4NFofTZRRiLZS5p7"
+	);
+	assert_eq!(
+		b"Hello World!".to_vec().base58ify_with(Base58Alphabet::Flickr),
+		"2nePN7syqqRkyrH2t"
+	);
+	assert_eq!([0; 21].base58ify_check(), "1111111111111111111114oLvT2");
+	assert_eq!(b"".base58ify_check(), "3QJmnh");
+	assert_eq!(
+		b"\x00\x01\x09\x66\x77\x60\x06\x95\x3d\x55\x67\x43\x9e\x5e\x39\xf8\x6a\x0d\x27\x3b\xee"
+			.base58ify_check(),
+		"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"
+	);
+}
+
+/// Debase58ify the given Base58 to `Self`.
+///
+/// The invalid characters are reported with [`Error::InvalidCharacter`] or [`Error::InvalidByte`],
+/// and a wrong Base58Check checksum is reported with [`Error::MismatchedChecksum`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Base58Alphabet, Debase58ify, Error, LengthUnit};
+///
+/// // `[u8; N]`.
+/// assert_eq!(<[u8; 12]>::debase58ify("2NEpo7TZRRrLZSi2U"), Ok(*b"Hello World!"));
+/// assert_eq!(
+/// 	<[u8; 13]>::debase58ify("2NEpo7TZRRrLZSi2U"),
+/// 	Err(Error::MismatchedLength { expect: 13, actual: 12, unit: LengthUnit::Byte })
+/// );
+/// // `Vec<u8>`.
+/// assert_eq!(
+/// 	<Vec<u8>>::debase58ify_with("2nePN7syqqRkyrH2t", Base58Alphabet::Flickr),
+/// 	Ok(b"Hello World!".to_vec())
+/// );
+/// assert_eq!(
+/// 	<Vec<u8>>::debase58ify("2NEpo7TZRRrLZSi0U"),
+/// 	Err(Error::InvalidCharacter { character: '0', index: 15, char_index: 15 })
+/// );
+/// // Base58Check.
+/// assert_eq!(<[u8; 21]>::debase58ify_check("1111111111111111111114oLvT2"), Ok([0; 21]));
+/// assert_eq!(
+/// 	<[u8; 21]>::debase58ify_check("1111111111111111111114oLvT3"),
+/// 	Err(Error::MismatchedChecksum { expect: 0x94a0_0911, actual: 0x94a0_0912 })
+/// );
+/// ```
+pub trait Debase58ify
+where
+	Self: Sized,
+{
+	/// Debase58ify `Self` from Base58 with the Bitcoin alphabet.
+	fn debase58ify<B>(base58: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase58ify `Self` from Base58 with the given alphabet.
+	fn debase58ify_with<B>(base58: B, alphabet: Base58Alphabet) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase58ify `Self` from Base58Check with the Bitcoin alphabet.
+	///
+	/// The checksum is verified and stripped. The decoded data must be at least 4 bytes long, or
+	/// [`Error::InsufficientLength`] is returned.
+	fn debase58ify_check<B>(base58: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase58ify `Self` from Base58Check with the given alphabet.
+	fn debase58ify_check_with<B>(base58: B, alphabet: Base58Alphabet) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+}
+macro_rules! debase58ify_fns {
+	($f:expr) => {
+		fn debase58ify<B>(base58: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			debase58ify(base58.as_ref(), Base58Alphabet::Bitcoin).and_then($f)
+		}
+
+		fn debase58ify_with<B>(base58: B, alphabet: Base58Alphabet) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			debase58ify(base58.as_ref(), alphabet).and_then($f)
+		}
+
+		fn debase58ify_check<B>(base58: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			debase58ify_check(base58.as_ref(), Base58Alphabet::Bitcoin).and_then($f)
+		}
+
+		fn debase58ify_check_with<B>(base58: B, alphabet: Base58Alphabet) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			debase58ify_check(base58.as_ref(), alphabet).and_then($f)
+		}
+	};
+}
+impl<const N: usize> Debase58ify for [u8; N] {
+	debase58ify_fns! { |bytes: Vec<u8>| {
+		let actual = bytes.len();
+
+		<[u8; N]>::try_from(bytes).map_err(|_| Error::MismatchedLength {
+			expect: N,
+			actual,
+			unit: LengthUnit::Byte,
+		})
+	}}
+}
+impl Debase58ify for Vec<u8> {
+	debase58ify_fns! { Ok }
+}
+#[test]
+fn debase58ify_should_work() {
+	assert_eq!(<Vec<u8>>::debase58ify(""), Ok(Vec::new()));
+	assert_eq!(<Vec<u8>>::debase58ify("1"), Ok(alloc::vec![0]));
+	assert_eq!(<Vec<u8>>::debase58ify("112"), Ok(alloc::vec![0, 0, 1]));
+	assert_eq!(<[u8; 4]>::debase58ify("7YXq9G"), Ok([0xff; 4]));
+	assert_eq!(<[u8; 12]>::debase58ify("2NEpo7TZRRrLZSi2U"), Ok(*b"Hello World!"));
+	assert_eq!(
+		<[u8; 12]>::debase58ify_with("p4NFofTZRRiLZS5p7", Base58Alphabet::Ripple),
+		Ok(*b"Hello World!")
+	);
+	assert_eq!(
+		<[u8; 12]>::debase58ify_with("2nePN7syqqRkyrH2t", Base58Alphabet::Flickr),
+		Ok(*b"Hello World!")
+	);
+	assert_eq!(
+		<Vec<u8>>::debase58ify_check("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"),
+		Ok(b"\x00\x01\x09\x66\x77\x60\x06\x95\x3d\x55\x67\x43\x9e\x5e\x39\xf8\x6a\x0d\x27\x3b\xee"
+			.to_vec())
+	);
+	assert_eq!(<Vec<u8>>::debase58ify_check("3QJmnh"), Ok(Vec::new()));
+
+	assert_eq!(
+		<Vec<u8>>::debase58ify("2NEpo7TZRRrLZSi2l"),
+		Err(Error::InvalidCharacter { character: 'l', index: 16, char_index: 16 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase58ify("2NEpo7我"),
+		Err(Error::InvalidCharacter { character: '我', index: 6, char_index: 6 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase58ify(b"2NEpo7\xff"),
+		Err(Error::InvalidByte { byte: 0xff, index: 6 })
+	);
+	assert_eq!(
+		<[u8; 11]>::debase58ify("2NEpo7TZRRrLZSi2U"),
+		Err(Error::MismatchedLength { expect: 11, actual: 12, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase58ify_check("2NEpo"),
+		Err(Error::InsufficientLength { min: 4, actual: 3, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase58ify_check("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN"),
+		Err(Error::MismatchedChecksum { expect: 0xd61967f6, actual: 0xd61967f7 })
+	);
+}
+
+fn base58ify(bytes: &[u8], alphabet: Base58Alphabet) -> String {
+	let chars = alphabet.chars();
+	let zeros = bytes.iter().take_while(|b| **b == 0).count();
+	// The digits in little-endian, `log(256) / log(58)` is about `1.37`.
+	let mut digits = Vec::with_capacity((bytes.len() - zeros) * 138 / 100 + 1);
+
+	for &byte in &bytes[zeros..] {
+		let mut carry = byte as u32;
+
+		for digit in digits.iter_mut() {
+			carry += (*digit as u32) << 8;
+			*digit = (carry % 58) as u8;
+			carry /= 58;
+		}
+		while carry > 0 {
+			digits.push((carry % 58) as u8);
+
+			carry /= 58;
+		}
+	}
+
+	iter::repeat_n(chars[0] as char, zeros)
+		.chain(digits.iter().rev().map(|d| chars[*d as usize] as char))
+		.collect()
+}
+
+fn base58ify_check(bytes: &[u8], alphabet: Base58Alphabet) -> String {
+	let mut bytes_with_checksum = Vec::with_capacity(bytes.len() + 4);
+
+	bytes_with_checksum.extend_from_slice(bytes);
+	bytes_with_checksum.extend_from_slice(&hash::sha256d(bytes)[..4]);
+
+	base58ify(&bytes_with_checksum, alphabet)
+}
+
+fn debase58ify(base58: &[u8], alphabet: Base58Alphabet) -> Result<Vec<u8>> {
+	let (zero, table) = (alphabet.chars()[0], alphabet.table());
+	let zeros = base58.iter().take_while(|c| **c == zero).count();
+	// The bytes in little-endian, `log(58) / log(256)` is about `0.733`.
+	let mut bytes = Vec::with_capacity((base58.len() - zeros) * 733 / 1000 + 1);
+
+	for (i, &c) in base58.iter().enumerate().skip(zeros) {
+		// The characters before `i` are all checked, they are ASCII; qed.
+		let mut carry =
+			table[c as usize].ok_or_else(|| Error::invalid_character(&base58[i..], i, i))? as u32;
+
+		for byte in bytes.iter_mut() {
+			carry += *byte as u32 * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.push(carry as u8);
+
+			carry >>= 8;
+		}
+	}
+
+	bytes.extend(iter::repeat_n(0, zeros));
+	bytes.reverse();
+
+	Ok(bytes)
+}
+
+fn debase58ify_check(base58: &[u8], alphabet: Base58Alphabet) -> Result<Vec<u8>> {
+	let mut bytes = debase58ify(base58, alphabet)?;
+
+	if bytes.len() < 4 {
+		Err(Error::InsufficientLength { min: 4, actual: bytes.len(), unit: LengthUnit::Byte })?;
+	}
+
+	let checksum = bytes.split_off(bytes.len() - 4);
+	let expect = u32::from_be_bytes(hash::sha256d(&bytes)[..4].try_into().expect("4 bytes; qed"));
+	let actual = u32::from_be_bytes(checksum.try_into().expect("4 bytes; qed"));
+
+	if expect != actual {
+		Err(Error::MismatchedChecksum { expect, actual })?;
+	}
+
+	Ok(bytes)
+}
@@ -0,0 +1,346 @@
+// self
+use crate::{packing::Packing, prelude::*};
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+static STANDARD2DIGIT: [Option<u8>; 256] = char2digit(STANDARD);
+static URL2DIGIT: [Option<u8>; 256] = char2digit(URL);
+
+const fn char2digit(alphabet: &[u8; 64]) -> [Option<u8>; 256] {
+	let mut table = [None; 256];
+	let mut i = 0;
+
+	while i < 64 {
+		table[alphabet[i] as usize] = Some(i as u8);
+
+		i += 1;
+	}
+
+	table
+}
+
+/// The Base64 variants, the alphabet and whether the output is padded with `=`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base64Variant {
+	/// The standard alphabet with padding, RFC 4648 section 4.
+	#[default]
+	Standard,
+	/// The standard alphabet without padding.
+	StandardUnpadded,
+	/// The URL and filename safe alphabet with padding, RFC 4648 section 5.
+	Url,
+	/// The URL and filename safe alphabet without padding.
+	UrlUnpadded,
+}
+impl Base64Variant {
+	fn chars(self) -> &'static [u8; 64] {
+		match self {
+			Self::Standard | Self::StandardUnpadded => STANDARD,
+			Self::Url | Self::UrlUnpadded => URL,
+		}
+	}
+
+	fn table(self) -> &'static [Option<u8>; 256] {
+		match self {
+			Self::Standard | Self::StandardUnpadded => &STANDARD2DIGIT,
+			Self::Url | Self::UrlUnpadded => &URL2DIGIT,
+		}
+	}
+
+	fn padded(self) -> bool {
+		matches!(self, Self::Standard | Self::Url)
+	}
+
+	pub(crate) fn packing(self) -> Packing {
+		Packing { bits: 6, chars: self.chars(), table: self.table(), padded: self.padded() }
+	}
+}
+
+/// Base64ify `Self`.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Base64ify;
+///
+/// // `[u8; N]`.
+/// assert_eq!(b"Love".base64ify(), "TG92ZQ==");
+/// // `&[u8]`.
+/// assert_eq!(b"Love".as_slice().base64ify_unpadded(), "TG92ZQ");
+/// // `Vec<u8>`.
+/// assert_eq!(vec![0xfb, 0xff].base64ify_url(), "-_8=");
+/// assert_eq!(vec![0xfb, 0xff].base64ify_url_unpadded(), "-_8");
+/// ```
+pub trait Base64ify {
+	/// Base64ify `Self` with the standard alphabet and padding.
+	fn base64ify(&self) -> String;
+
+	/// Base64ify `Self` with the standard alphabet and without padding.
+	fn base64ify_unpadded(&self) -> String;
+
+	/// Base64ify `Self` with the URL-safe alphabet and padding.
+	fn base64ify_url(&self) -> String;
+
+	/// Base64ify `Self` with the URL-safe alphabet and without padding.
+	fn base64ify_url_unpadded(&self) -> String;
+}
+macro_rules! base64ify_bytes_fns {
+	() => {
+		fn base64ify(&self) -> String {
+			base64ify(self, Base64Variant::Standard)
+		}
+
+		fn base64ify_unpadded(&self) -> String {
+			base64ify(self, Base64Variant::StandardUnpadded)
+		}
+
+		fn base64ify_url(&self) -> String {
+			base64ify(self, Base64Variant::Url)
+		}
+
+		fn base64ify_url_unpadded(&self) -> String {
+			base64ify(self, Base64Variant::UrlUnpadded)
+		}
+	};
+}
+impl<const N: usize> Base64ify for [u8; N] {
+	base64ify_bytes_fns! {}
+}
+impl Base64ify for [u8] {
+	base64ify_bytes_fns! {}
+}
+impl Base64ify for Vec<u8> {
+	base64ify_bytes_fns! {}
+}
+#[test]
+fn base64ify_should_work() {
+	// RFC 4648 section 10.
+	assert_eq!(b"".base64ify(), "");
+	assert_eq!(b"f".base64ify(), "Zg==");
+	assert_eq!(b"fo".base64ify(), "Zm8=");
+	assert_eq!(b"foo".base64ify(), "Zm9v");
+	assert_eq!(b"foob".base64ify(), "Zm9vYg==");
+	assert_eq!(b"fooba".base64ify(), "Zm9vYmE=");
+	assert_eq!(b"foobar".base64ify(), "Zm9vYmFy");
+	assert_eq!(b"f".base64ify_unpadded(), "Zg");
+	assert_eq!(b"fo".as_slice().base64ify_unpadded(), "Zm8");
+	assert_eq!(b"foo".to_vec().base64ify_unpadded(), "Zm9v");
+	assert_eq!([0xfb, 0xff, 0xbf].base64ify(), "+/+/");
+	assert_eq!([0xfb, 0xff, 0xbf].base64ify_url(), "-_-_");
+	assert_eq!([0xfb].base64ify_url(), "-w==");
+	assert_eq!([0xfb].base64ify_url_unpadded(), "-w");
+}
+
+/// Debase64ify the given Base64 to `Self`.
+///
+/// The decoding is strict. The invalid characters are reported with [`Error::InvalidCharacter`] or
+/// [`Error::InvalidByte`], missing, extra or misplaced `=` with [`Error::InvalidPadding`], and the
+/// non-canonical encodings, whose unused bits are not zero, with [`Error::InvalidTrailingBits`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Debase64ify, Error, LengthUnit};
+///
+/// // `[u8; N]`.
+/// assert_eq!(<[u8; 4]>::debase64ify("TG92ZQ=="), Ok(*b"Love"));
+/// assert_eq!(
+/// 	<[u8; 5]>::debase64ify("TG92ZQ=="),
+/// 	Err(Error::MismatchedLength { expect: 5, actual: 4, unit: LengthUnit::Byte })
+/// );
+/// // `Vec<u8>`.
+/// assert_eq!(<Vec<u8>>::debase64ify_unpadded("TG92ZQ"), Ok(b"Love".to_vec()));
+/// assert_eq!(<Vec<u8>>::debase64ify_url("-_8="), Ok(vec![0xfb, 0xff]));
+/// assert_eq!(<Vec<u8>>::debase64ify_url_unpadded("-_8"), Ok(vec![0xfb, 0xff]));
+/// // Strict.
+/// assert_eq!(<Vec<u8>>::debase64ify("TG92ZQ"), Err(Error::InvalidPadding { index: 6 }));
+/// assert_eq!(<Vec<u8>>::debase64ify("TG92ZR=="), Err(Error::InvalidTrailingBits { index: 5 }));
+/// assert_eq!(
+/// 	<Vec<u8>>::debase64ify_url("+/8="),
+/// 	Err(Error::InvalidCharacter { character: '+', index: 0, char_index: 0 })
+/// );
+/// ```
+pub trait Debase64ify
+where
+	Self: Sized,
+{
+	/// Debase64ify `Self` from Base64 with the standard alphabet and padding.
+	fn debase64ify<B>(base64: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase64ify `Self` from Base64 with the standard alphabet and without padding.
+	fn debase64ify_unpadded<B>(base64: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase64ify `Self` from Base64 with the URL-safe alphabet and padding.
+	fn debase64ify_url<B>(base64: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debase64ify `Self` from Base64 with the URL-safe alphabet and without padding.
+	fn debase64ify_url_unpadded<B>(base64: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+}
+macro_rules! debase64ify_fns {
+	($f:ident) => {
+		fn debase64ify<B>(base64: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base64.as_ref(), Base64Variant::Standard)
+		}
+
+		fn debase64ify_unpadded<B>(base64: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base64.as_ref(), Base64Variant::StandardUnpadded)
+		}
+
+		fn debase64ify_url<B>(base64: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base64.as_ref(), Base64Variant::Url)
+		}
+
+		fn debase64ify_url_unpadded<B>(base64: B) -> Result<Self>
+		where
+			B: AsRef<[u8]>,
+		{
+			$f(base64.as_ref(), Base64Variant::UrlUnpadded)
+		}
+	};
+}
+impl<const N: usize> Debase64ify for [u8; N] {
+	debase64ify_fns! { debase64ify_array }
+}
+impl Debase64ify for Vec<u8> {
+	debase64ify_fns! { debase64ify_vec }
+}
+#[test]
+fn debase64ify_should_work() {
+	assert_eq!(<Vec<u8>>::debase64ify(""), Ok(Vec::new()));
+	assert_eq!(<Vec<u8>>::debase64ify("Zg=="), Ok(b"f".to_vec()));
+	assert_eq!(<Vec<u8>>::debase64ify("Zm8="), Ok(b"fo".to_vec()));
+	assert_eq!(<Vec<u8>>::debase64ify("Zm9v"), Ok(b"foo".to_vec()));
+	assert_eq!(<Vec<u8>>::debase64ify("Zm9vYg=="), Ok(b"foob".to_vec()));
+	assert_eq!(<Vec<u8>>::debase64ify("Zm9vYmE="), Ok(b"fooba".to_vec()));
+	assert_eq!(<[u8; 6]>::debase64ify("Zm9vYmFy"), Ok(*b"foobar"));
+	assert_eq!(<[u8; 0]>::debase64ify_unpadded(""), Ok([]));
+	assert_eq!(<[u8; 1]>::debase64ify_unpadded("Zg"), Ok(*b"f"));
+	assert_eq!(<[u8; 2]>::debase64ify_unpadded("Zm8"), Ok(*b"fo"));
+	assert_eq!(<[u8; 3]>::debase64ify_url("-_-_"), Ok([0xfb, 0xff, 0xbf]));
+	assert_eq!(<[u8; 1]>::debase64ify_url_unpadded("-w"), Ok([0xfb]));
+
+	// Padding.
+	assert_eq!(<Vec<u8>>::debase64ify("Zg="), Err(Error::InvalidPadding { index: 3 }));
+	assert_eq!(<Vec<u8>>::debase64ify("Z==="), Err(Error::InvalidPadding { index: 1 }));
+	assert_eq!(<Vec<u8>>::debase64ify("===="), Err(Error::InvalidPadding { index: 0 }));
+	assert_eq!(<Vec<u8>>::debase64ify_unpadded("Zm9vY"), Err(Error::InvalidPadding { index: 4 }));
+	assert_eq!(
+		<Vec<u8>>::debase64ify("Zg==Zg=="),
+		Err(Error::InvalidCharacter { character: '=', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase64ify("Z=g="),
+		Err(Error::InvalidCharacter { character: '=', index: 1, char_index: 1 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase64ify_unpadded("Zg=="),
+		Err(Error::InvalidCharacter { character: '=', index: 2, char_index: 2 })
+	);
+	// Canonical.
+	assert_eq!(<Vec<u8>>::debase64ify("Zh=="), Err(Error::InvalidTrailingBits { index: 1 }));
+	assert_eq!(<Vec<u8>>::debase64ify("Zm9="), Err(Error::InvalidTrailingBits { index: 2 }));
+	assert_eq!(
+		<Vec<u8>>::debase64ify_unpadded("Zm9"),
+		Err(Error::InvalidTrailingBits { index: 2 })
+	);
+	// Characters.
+	assert_eq!(
+		<Vec<u8>>::debase64ify("Zm9v我="),
+		Err(Error::InvalidCharacter { character: '我', index: 4, char_index: 4 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase64ify(b"Zm9\xff"),
+		Err(Error::InvalidByte { byte: 0xff, index: 3 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debase64ify("-_-_"),
+		Err(Error::InvalidCharacter { character: '-', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		<[u8; 2]>::debase64ify("Zm9v"),
+		Err(Error::MismatchedLength { expect: 2, actual: 3, unit: LengthUnit::Byte })
+	);
+}
+
+/// Debase64ify Base64 into a mutable slice source.
+///
+/// The slice must be exactly as long as the decoded bytes.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Base64Variant, Error, LengthUnit};
+///
+/// let mut bytes = [0; 4];
+///
+/// assert_eq!(
+/// 	array_bytes::debase64ify_slice_mut("TG92ZQ==", &mut bytes, Base64Variant::Standard),
+/// 	Ok(b"Love".as_slice())
+/// );
+/// assert_eq!(
+/// 	array_bytes::debase64ify_slice_mut("TG92ZQ", &mut bytes, Base64Variant::Standard),
+/// 	Err(Error::InvalidPadding { index: 6 })
+/// );
+/// assert_eq!(
+/// 	array_bytes::debase64ify_slice_mut("TG92", &mut bytes, Base64Variant::UrlUnpadded),
+/// 	Err(Error::MismatchedLength { expect: 4, actual: 3, unit: LengthUnit::Byte })
+/// );
+/// ```
+pub fn debase64ify_slice_mut<B>(
+	base64: B,
+	slice_src: &mut [u8],
+	variant: Base64Variant,
+) -> Result<&[u8]>
+where
+	B: AsRef<[u8]>,
+{
+	variant.packing().decode_slice_mut(base64.as_ref(), slice_src)
+}
+#[test]
+fn debase64ify_slice_mut_should_work() {
+	let mut bytes = [0; 6];
+
+	assert_eq!(
+		debase64ify_slice_mut("Zm9vYmFy", &mut bytes, Base64Variant::Standard),
+		Ok(b"foobar".as_slice())
+	);
+	assert_eq!(
+		debase64ify_slice_mut("Zm9vYg", &mut bytes[..4], Base64Variant::UrlUnpadded),
+		Ok(b"foob".as_slice())
+	);
+	assert_eq!(
+		debase64ify_slice_mut("Zm9vYg", &mut bytes, Base64Variant::StandardUnpadded),
+		Err(Error::MismatchedLength { expect: 6, actual: 4, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		debase64ify_slice_mut("Zm9vYm.y", &mut bytes, Base64Variant::Url),
+		Err(Error::InvalidCharacter { character: '.', index: 6, char_index: 6 })
+	);
+}
+
+fn base64ify(bytes: &[u8], variant: Base64Variant) -> String {
+	variant.packing().encode(bytes)
+}
+
+fn debase64ify_array<const N: usize>(base64: &[u8], variant: Base64Variant) -> Result<[u8; N]> {
+	variant.packing().decode_array(base64)
+}
+
+fn debase64ify_vec(base64: &[u8], variant: Base64Variant) -> Result<Vec<u8>> {
+	variant.packing().decode_vec(base64)
+}
@@ -0,0 +1,409 @@
+// self
+use crate::prelude::*;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const CHECKSUM_LEN: usize = 6;
+// The limits of BIP-173.
+const MAX_LEN: usize = 90;
+const MAX_HRP_LEN: usize = 83;
+
+static CHAR2DIGIT: [Option<u8>; 256] = char2digit();
+
+const fn char2digit() -> [Option<u8>; 256] {
+	let mut table = [None; 256];
+	let mut i = 0;
+
+	while i < 32 {
+		table[CHARSET[i] as usize] = Some(i as u8);
+		table[CHARSET[i].to_ascii_uppercase() as usize] = Some(i as u8);
+
+		i += 1;
+	}
+
+	table
+}
+
+// The variants differ in the checksum constant only.
+#[derive(Clone, Copy)]
+enum Bech32Variant {
+	// BIP-173, used by Cosmos and segwit v0.
+	Bech32,
+	// BIP-350, used by segwit v1 and above.
+	Bech32m,
+}
+impl Bech32Variant {
+	fn constant(self) -> u32 {
+		match self {
+			Self::Bech32 => 1,
+			Self::Bech32m => 0x2bc830a3,
+		}
+	}
+}
+
+/// Bech32ify `Self` with the human-readable part.
+///
+/// The bytes are regrouped into 5-bit groups, padded with zeros. The HRP must be 1 to 83 characters
+/// in the range of `!` to `~` without mixed case, it's written in lowercase. The whole output must
+/// not exceed 90 characters.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Bech32ify, Error};
+///
+/// let account = (0..20).collect::<Vec<u8>>();
+///
+/// assert_eq!(
+/// 	account.bech32ify("cosmos"),
+/// 	Ok("cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnrk363e".into())
+/// );
+/// assert_eq!(
+/// 	account.bech32mify("cosmos"),
+/// 	Ok("cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnk2pk5m".into())
+/// );
+/// assert_eq!(
+/// 	account.bech32ify("cos mos"),
+/// 	Err(Error::InvalidCharacter { character: ' ', index: 3, char_index: 3 })
+/// );
+/// ```
+pub trait Bech32ify {
+	/// Bech32ify `Self` with the Bech32 checksum.
+	fn bech32ify(&self, hrp: &str) -> Result<String>;
+
+	/// Bech32ify `Self` with the Bech32m checksum.
+	fn bech32mify(&self, hrp: &str) -> Result<String>;
+}
+macro_rules! bech32ify_bytes_fns {
+	() => {
+		fn bech32ify(&self, hrp: &str) -> Result<String> {
+			bech32ify(hrp, self, Bech32Variant::Bech32)
+		}
+
+		fn bech32mify(&self, hrp: &str) -> Result<String> {
+			bech32ify(hrp, self, Bech32Variant::Bech32m)
+		}
+	};
+}
+impl<const N: usize> Bech32ify for [u8; N] {
+	bech32ify_bytes_fns! {}
+}
+impl Bech32ify for [u8] {
+	bech32ify_bytes_fns! {}
+}
+impl Bech32ify for Vec<u8> {
+	bech32ify_bytes_fns! {}
+}
+#[test]
+fn bech32ify_should_work() {
+	assert_eq!([].bech32ify("a"), Ok("a12uel5l".into()));
+	assert_eq!([].bech32mify("A"), Ok("a1lqfn3a".into()));
+	assert_eq!([1].as_slice().bech32ify("abc"), Ok("abc1qy928epu".into()));
+	assert_eq!(
+		[0xff; 32].to_vec().bech32mify("bc"),
+		Ok("bc1llllllllllllllllllllllllllllllllllllllllllllllllllls2sd8mx".into())
+	);
+
+	assert_eq!([].bech32ify(""), Err(Error::InvalidHrpLength { length: 0 }));
+	assert_eq!([].bech32ify(&"a".repeat(84)), Err(Error::InvalidHrpLength { length: 84 }));
+	assert_eq!(
+		[].bech32ify("aB"),
+		Err(Error::InvalidCharacter { character: 'B', index: 1, char_index: 1 })
+	);
+	assert_eq!(
+		[].bech32ify("a我"),
+		Err(Error::InvalidCharacter { character: '我', index: 1, char_index: 1 })
+	);
+	assert_eq!([0; 52].bech32ify("a"), Err(Error::CapacityOverflow { capacity: 90, length: 92 }));
+}
+
+/// Debech32ify the given Bech32 to the human-readable part and `Self`.
+///
+/// The decoding follows BIP-173, the input must not exceed 90 characters or mix cases, and the
+/// separator is the last `1`. The errors point at the offending positions: the invalid characters
+/// with [`Error::InvalidCharacter`], a missing or misplaced separator with
+/// [`Error::InvalidSeparator`], a human-readable part of the wrong length with
+/// [`Error::InvalidHrpLength`], a wrong checksum with [`Error::MismatchedChecksum`], and the data
+/// that doesn't regroup into bytes with [`Error::InvalidPadding`] or
+/// [`Error::InvalidTrailingBits`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Debech32ify, Error};
+///
+/// let (hrp, account) =
+/// 	<[u8; 20]>::debech32ify("cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnrk363e").unwrap();
+///
+/// assert_eq!(hrp, "cosmos");
+/// assert_eq!(account[19], 19);
+/// assert_eq!(
+/// 	<Vec<u8>>::debech32mify("COSMOS1QQQSYQCYQ5RQWZQFPG9SCRGWPUGPZYSNK2PK5M")
+/// 		.map(|(hrp, _)| hrp),
+/// 	Ok("cosmos".into())
+/// );
+/// assert_eq!(
+/// 	<Vec<u8>>::debech32ify("cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnrk363b"),
+/// 	Err(Error::InvalidCharacter { character: 'b', index: 44, char_index: 44 })
+/// );
+/// ```
+pub trait Debech32ify
+where
+	Self: Sized,
+{
+	/// Debech32ify the human-readable part and `Self` with the Bech32 checksum.
+	fn debech32ify<B>(bech32: B) -> Result<(String, Self)>
+	where
+		B: AsRef<[u8]>;
+
+	/// Debech32ify the human-readable part and `Self` with the Bech32m checksum.
+	fn debech32mify<B>(bech32: B) -> Result<(String, Self)>
+	where
+		B: AsRef<[u8]>;
+}
+macro_rules! debech32ify_fns {
+	($f:expr) => {
+		fn debech32ify<B>(bech32: B) -> Result<(String, Self)>
+		where
+			B: AsRef<[u8]>,
+		{
+			debech32ify(bech32.as_ref(), Bech32Variant::Bech32)
+				.and_then(|(hrp, bytes)| Ok((hrp, $f(bytes)?)))
+		}
+
+		fn debech32mify<B>(bech32: B) -> Result<(String, Self)>
+		where
+			B: AsRef<[u8]>,
+		{
+			debech32ify(bech32.as_ref(), Bech32Variant::Bech32m)
+				.and_then(|(hrp, bytes)| Ok((hrp, $f(bytes)?)))
+		}
+	};
+}
+impl<const N: usize> Debech32ify for [u8; N] {
+	debech32ify_fns! { |bytes: Vec<u8>| {
+		let actual = bytes.len();
+
+		<[u8; N]>::try_from(bytes).map_err(|_| Error::MismatchedLength {
+			expect: N,
+			actual,
+			unit: LengthUnit::Byte,
+		})
+	}}
+}
+impl Debech32ify for Vec<u8> {
+	debech32ify_fns! { Ok::<_, Error> }
+}
+#[test]
+fn debech32ify_should_work() {
+	assert_eq!(<Vec<u8>>::debech32ify("A12UEL5L"), Ok(("a".into(), Vec::new())));
+	assert_eq!(<[u8; 0]>::debech32mify("a1lqfn3a"), Ok(("a".into(), [])));
+	assert_eq!(<[u8; 1]>::debech32ify("abc1qy928epu"), Ok(("abc".into(), [1])));
+	// The data part of BIP-173's `abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw` regroups into
+	// bytes without padding.
+	assert_eq!(
+		<[u8; 20]>::debech32ify("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw").map(|(_, b)| b[0]),
+		Ok(0x00)
+	);
+	assert_eq!(
+		<[u8; 32]>::debech32mify("bc1llllllllllllllllllllllllllllllllllllllllllllllllllls2sd8mx"),
+		Ok(("bc".into(), [0xff; 32]))
+	);
+
+	// Wrong variant.
+	assert_eq!(
+		<Vec<u8>>::debech32mify("a12uel5l"),
+		Err(Error::MismatchedChecksum { expect: 0x2bc830a3, actual: 1 })
+	);
+	// Characters.
+	assert_eq!(
+		<Vec<u8>>::debech32ify("a12uel5i"),
+		Err(Error::InvalidCharacter { character: 'i', index: 7, char_index: 7 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debech32ify("a 12uel5l"),
+		Err(Error::InvalidCharacter { character: ' ', index: 1, char_index: 1 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debech32ify("A12uEL5L"),
+		Err(Error::InvalidCharacter { character: 'u', index: 3, char_index: 3 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debech32ify("a12uel我"),
+		Err(Error::InvalidCharacter { character: '我', index: 6, char_index: 6 })
+	);
+	// Separator.
+	assert_eq!(<Vec<u8>>::debech32ify("pzry9x0s0muk"), Err(Error::InvalidSeparator { index: 12 }));
+	assert_eq!(<Vec<u8>>::debech32ify("1pzry9x0s0muk"), Err(Error::InvalidHrpLength { length: 0 }));
+	assert_eq!(
+		<Vec<u8>>::debech32ify("a".repeat(84) + "1qqqqq"),
+		Err(Error::InvalidHrpLength { length: 84 })
+	);
+	assert_eq!(<Vec<u8>>::debech32ify("a1uel5l"), Err(Error::InvalidSeparator { index: 1 }));
+	// Regrouping, one digit has only 5 bits and the trailing bits of two digits are `01`.
+	assert_eq!(<Vec<u8>>::debech32ify("a1pv7wwwr"), Err(Error::InvalidPadding { index: 2 }));
+	assert_eq!(<Vec<u8>>::debech32ify("a1qpamnt9j"), Err(Error::InvalidTrailingBits { index: 3 }));
+	// Length.
+	assert_eq!(
+		<Vec<u8>>::debech32ify("a".repeat(91)),
+		Err(Error::CapacityOverflow { capacity: 90, length: 91 })
+	);
+	assert_eq!(
+		<[u8; 19]>::debech32ify("cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnrk363e"),
+		Err(Error::MismatchedLength { expect: 19, actual: 20, unit: LengthUnit::Byte })
+	);
+}
+
+fn bech32ify(hrp: &str, bytes: &[u8], variant: Bech32Variant) -> Result<String> {
+	let hrp = hrp.as_bytes();
+
+	if hrp.is_empty() || hrp.len() > MAX_HRP_LEN {
+		Err(Error::InvalidHrpLength { length: hrp.len() })?;
+	}
+
+	check_chars(hrp)?;
+
+	let hrp = hrp.to_ascii_lowercase();
+	let mut digits = regroup_8_to_5(bytes);
+	let length = hrp.len() + 1 + digits.len() + CHECKSUM_LEN;
+
+	if length > MAX_LEN {
+		Err(Error::CapacityOverflow { capacity: MAX_LEN, length })?;
+	}
+
+	let checksum = polymod(&hrp, &digits, &[0; CHECKSUM_LEN]) ^ variant.constant();
+
+	digits.extend((0..CHECKSUM_LEN).rev().map(|i| (checksum >> (i * 5)) as u8 & 0x1f));
+
+	let mut bech32 = String::with_capacity(length);
+
+	bech32.extend(hrp.iter().map(|c| *c as char));
+	bech32.push('1');
+	bech32.extend(digits.iter().map(|d| CHARSET[*d as usize] as char));
+
+	Ok(bech32)
+}
+
+fn debech32ify(bech32: &[u8], variant: Bech32Variant) -> Result<(String, Vec<u8>)> {
+	if bech32.len() > MAX_LEN {
+		Err(Error::CapacityOverflow { capacity: MAX_LEN, length: bech32.len() })?;
+	}
+
+	check_chars(bech32)?;
+
+	let separator = bech32
+		.iter()
+		.rposition(|c| *c == b'1')
+		.ok_or(Error::InvalidSeparator { index: bech32.len() })?;
+
+	if separator == 0 || separator > MAX_HRP_LEN {
+		Err(Error::InvalidHrpLength { length: separator })?;
+	}
+	if bech32.len() - separator - 1 < CHECKSUM_LEN {
+		Err(Error::InvalidSeparator { index: separator })?;
+	}
+
+	let hrp = bech32[..separator].to_ascii_lowercase();
+	let digits = bech32[separator + 1..]
+		.iter()
+		.enumerate()
+		.map(|(i, c)| {
+			let i = separator + 1 + i;
+
+			// All the characters are checked to be ASCII; qed.
+			CHAR2DIGIT[*c as usize].ok_or_else(|| Error::invalid_character(&bech32[i..], i, i))
+		})
+		.collect::<Result<Vec<_>>>()?;
+	let actual = polymod(&hrp, &digits, &[]);
+
+	if actual != variant.constant() {
+		Err(Error::MismatchedChecksum { expect: variant.constant(), actual })?;
+	}
+
+	let bytes =
+		regroup_5_to_8(&digits[..digits.len() - CHECKSUM_LEN], bech32.len() - CHECKSUM_LEN - 1)?;
+
+	// All the characters are checked to be ASCII; qed.
+	Ok((hrp.iter().map(|c| *c as char).collect(), bytes))
+}
+
+// Check the characters are in the range of `!` to `~` without mixed case.
+fn check_chars(s: &[u8]) -> Result<()> {
+	let mut case = None;
+
+	for (i, c) in s.iter().enumerate() {
+		if !(b'!'..=b'~').contains(c) {
+			// The characters before `i` are all checked, they are ASCII; qed.
+			Err(Error::invalid_character(&s[i..], i, i))?;
+		}
+		if c.is_ascii_alphabetic()
+			&& *case.get_or_insert(c.is_ascii_uppercase()) != c.is_ascii_uppercase()
+		{
+			Err(Error::InvalidCharacter { character: *c as _, index: i, char_index: i })?;
+		}
+	}
+
+	Ok(())
+}
+
+fn polymod(hrp: &[u8], digits: &[u8], tail: &[u8]) -> u32 {
+	hrp.iter()
+		.map(|c| c >> 5)
+		.chain([0])
+		.chain(hrp.iter().map(|c| c & 0x1f))
+		.chain(digits.iter().copied())
+		.chain(tail.iter().copied())
+		.fold(1, |chk, d| {
+			let b = chk >> 25;
+
+			GENERATOR.iter().enumerate().fold((chk & 0x1ffffff) << 5 ^ d as u32, |chk, (i, g)| {
+				if (b >> i) & 1 == 1 { chk ^ g } else { chk }
+			})
+		})
+}
+
+fn regroup_8_to_5(bytes: &[u8]) -> Vec<u8> {
+	let mut digits = Vec::with_capacity((bytes.len() * 8).div_ceil(5) + CHECKSUM_LEN);
+	let (mut acc, mut bits) = (0_u32, 0);
+
+	for &byte in bytes {
+		acc = (acc << 8) | byte as u32;
+		bits += 8;
+
+		while bits >= 5 {
+			bits -= 5;
+
+			digits.push((acc >> bits) as u8 & 0x1f);
+		}
+	}
+
+	if bits > 0 {
+		digits.push((acc << (5 - bits)) as u8 & 0x1f);
+	}
+
+	digits
+}
+
+// `last` is the index of the last data character, where the padding problems are reported.
+fn regroup_5_to_8(digits: &[u8], last: usize) -> Result<Vec<u8>> {
+	let mut bytes = Vec::with_capacity(digits.len() * 5 / 8);
+	let (mut acc, mut bits) = (0_u32, 0);
+
+	for &digit in digits {
+		acc = (acc << 5) | digit as u32;
+		bits += 5;
+
+		if bits >= 8 {
+			bits -= 8;
+
+			bytes.push((acc >> bits) as u8);
+		}
+	}
+
+	if bits >= 5 {
+		Err(Error::InvalidPadding { index: last })?;
+	}
+	if acc & ((1 << bits) - 1) != 0 {
+		Err(Error::InvalidTrailingBits { index: last })?;
+	}
+
+	Ok(bytes)
+}
@@ -0,0 +1,522 @@
+// self
+use crate::prelude::*;
+
+// The largest power of 10 whose digits are converted at once.
+const CHUNK: u64 = 1_000_000_000;
+const CHUNK_DIGITS: usize = 9;
+
+/// The byte order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endianness {
+	/// The most significant byte first.
+	#[default]
+	Big,
+	/// The least significant byte first.
+	Little,
+}
+
+/// The format of a decimal.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{DecimalFormat, Endianness};
+///
+/// // 18 decimals, with `,` between the thousands.
+/// let format = DecimalFormat { separator: Some(','), decimals: 18, ..Default::default() };
+///
+/// assert_eq!(format.endianness, Endianness::Big);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DecimalFormat {
+	/// The byte order of the integer.
+	pub endianness: Endianness,
+	/// The thousands separator of the integer part.
+	///
+	/// It must not be a digit or `.`, or it couldn't be told apart from the digits.
+	pub separator: Option<char>,
+	/// The digits after the decimal point, the integer is the value scaled by `10^decimals`.
+	///
+	/// It must not exceed [`DecimalFormat::MAX_DECIMALS`].
+	pub decimals: usize,
+}
+impl DecimalFormat {
+	/// The maximum decimals, as many as an ERC-20 `uint8` can declare.
+	pub const MAX_DECIMALS: usize = u8::MAX as _;
+
+	fn check(self) -> Result<Self> {
+		if let Some(separator) = self.separator
+			&& (separator.is_ascii_digit() || separator == '.')
+		{
+			Err(Error::InvalidThousandsSeparator { separator })?;
+		}
+		if self.decimals > Self::MAX_DECIMALS {
+			Err(Error::InvalidDecimals { decimals: self.decimals, max: Self::MAX_DECIMALS })?;
+		}
+
+		Ok(self)
+	}
+}
+
+/// Decimalify `Self`, an unsigned integer of any width.
+///
+/// The separator which is a digit or `.` is reported with [`Error::InvalidThousandsSeparator`], and
+/// the decimals over [`DecimalFormat::MAX_DECIMALS`] with [`Error::InvalidDecimals`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::{DecimalFormat, Decimalify, Endianness, Error};
+///
+/// // `[u8; N]`.
+/// assert_eq!(u128::MAX.to_be_bytes().decimalify(), "340282366920938463463374607431768211455");
+/// // `&[u8]`.
+/// assert_eq!(
+/// 	1_234_567_u32.to_le_bytes().as_slice().decimalify_with(DecimalFormat {
+/// 		endianness: Endianness::Little,
+/// 		separator: Some(','),
+/// 		..Default::default()
+/// 	}),
+/// 	Ok("1,234,567".into())
+/// );
+/// // `Vec<u8>`.
+/// assert_eq!(
+/// 	1_500_000_000_000_u64
+/// 		.to_be_bytes()
+/// 		.to_vec()
+/// 		.decimalify_with(DecimalFormat { decimals: 12, ..Default::default() }),
+/// 	Ok("1.500000000000".into())
+/// );
+/// assert_eq!(
+/// 	[1].decimalify_with(DecimalFormat { decimals: 256, ..Default::default() }),
+/// 	Err(Error::InvalidDecimals { decimals: 256, max: 255 })
+/// );
+/// ```
+pub trait Decimalify {
+	/// Decimalify `Self` in big-endian.
+	fn decimalify(&self) -> String;
+
+	/// Decimalify `Self` with the given format.
+	fn decimalify_with(&self, format: DecimalFormat) -> Result<String>;
+}
+macro_rules! decimalify_bytes_fns {
+	() => {
+		fn decimalify(&self) -> String {
+			decimalify(self, DecimalFormat::default())
+		}
+
+		fn decimalify_with(&self, format: DecimalFormat) -> Result<String> {
+			Ok(decimalify(self, format.check()?))
+		}
+	};
+}
+impl<const N: usize> Decimalify for [u8; N] {
+	decimalify_bytes_fns! {}
+}
+impl Decimalify for [u8] {
+	decimalify_bytes_fns! {}
+}
+impl Decimalify for Vec<u8> {
+	decimalify_bytes_fns! {}
+}
+#[test]
+fn decimalify_should_work() {
+	let with = |separator, decimals| DecimalFormat { separator, decimals, ..Default::default() };
+
+	assert_eq!([].decimalify(), "0");
+	assert_eq!([0; 32].decimalify(), "0");
+	assert_eq!([1].decimalify(), "1");
+	assert_eq!(u64::MAX.to_be_bytes().decimalify(), "18446744073709551615");
+	assert_eq!(
+		[0xff; 32].decimalify(),
+		"115792089237316195423570985008687907853269984665640564039457584007913129639935"
+	);
+	assert_eq!(
+		1_000_000_000_u64.to_le_bytes().decimalify_with(DecimalFormat {
+			endianness: Endianness::Little,
+			..Default::default()
+		}),
+		Ok("1000000000".into())
+	);
+	// Separator.
+	assert_eq!([].decimalify_with(with(Some(','), 0)), Ok("0".into()));
+	assert_eq!(999_u16.to_be_bytes().decimalify_with(with(Some(','), 0)), Ok("999".into()));
+	assert_eq!(1_000_u16.to_be_bytes().decimalify_with(with(Some(','), 0)), Ok("1,000".into()));
+	assert_eq!(
+		123_456_789_u32.to_be_bytes().decimalify_with(with(Some('\u{202f}'), 0)),
+		Ok("123\u{202f}456\u{202f}789".into())
+	);
+	// Decimals.
+	assert_eq!([].decimalify_with(with(None, 3)), Ok("0.000".into()));
+	assert_eq!([5].decimalify_with(with(None, 3)), Ok("0.005".into()));
+	assert_eq!(1_000_u16.to_be_bytes().decimalify_with(with(None, 3)), Ok("1.000".into()));
+	assert_eq!(
+		12_345_678_u32.to_be_bytes().decimalify_with(with(Some(','), 2)),
+		Ok("123,456.78".into())
+	);
+	assert_eq!(
+		10_u128.pow(30).to_be_bytes().decimalify_with(with(Some(','), 18)),
+		Ok("1,000,000,000,000.000000000000000000".into())
+	);
+	// Format.
+	assert_eq!(
+		1_000_u16.to_be_bytes().decimalify_with(with(Some('5'), 0)),
+		Err(Error::InvalidThousandsSeparator { separator: '5' })
+	);
+	assert_eq!(
+		1_000_u16.to_be_bytes().decimalify_with(with(Some('.'), 0)),
+		Err(Error::InvalidThousandsSeparator { separator: '.' })
+	);
+	assert_eq!(
+		[1].decimalify_with(with(None, 256)),
+		Err(Error::InvalidDecimals { decimals: 256, max: 255 })
+	);
+	assert_eq!(
+		[1].decimalify_with(with(None, usize::MAX)),
+		Err(Error::InvalidDecimals { decimals: usize::MAX, max: 255 })
+	);
+}
+
+/// Dedecimalify the given decimal to `Self`, an unsigned integer of `N` bytes.
+///
+/// The digits must fit in the format. The separators, if any, must be between the thousands, and
+/// the fractional part must not have more digits than the decimals. The empty input is reported
+/// with [`Error::InsufficientLength`], the invalid format as [`Decimalify`] does, the misplaced or
+/// invalid characters with [`Error::InvalidCharacter`] or [`Error::InvalidByte`], and the value
+/// which doesn't fit in `N` bytes with [`Error::CapacityOverflow`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::{DecimalFormat, Dedecimalify, Error};
+///
+/// let format = DecimalFormat { separator: Some(','), decimals: 2, ..Default::default() };
+///
+/// assert_eq!(<[u8; 4]>::dedecimalify("1234567"), Ok(1_234_567_u32.to_be_bytes()));
+/// assert_eq!(<[u8; 4]>::dedecimalify_with("12,345.6", format), Ok(1_234_560_u32.to_be_bytes()));
+/// assert_eq!(
+/// 	<[u8; 4]>::dedecimalify_with("12,34.5", format),
+/// 	Err(Error::InvalidCharacter { character: ',', index: 2, char_index: 2 })
+/// );
+/// assert_eq!(
+/// 	<[u8; 1]>::dedecimalify("256"),
+/// 	Err(Error::CapacityOverflow { capacity: 1, length: 2 })
+/// );
+/// ```
+pub trait Dedecimalify
+where
+	Self: Sized,
+{
+	/// Dedecimalify `Self` in big-endian.
+	fn dedecimalify<D>(decimal: D) -> Result<Self>
+	where
+		D: AsRef<[u8]>;
+
+	/// Dedecimalify `Self` with the given format.
+	fn dedecimalify_with<D>(decimal: D, format: DecimalFormat) -> Result<Self>
+	where
+		D: AsRef<[u8]>;
+}
+impl<const N: usize> Dedecimalify for [u8; N] {
+	fn dedecimalify<D>(decimal: D) -> Result<Self>
+	where
+		D: AsRef<[u8]>,
+	{
+		dedecimalify(decimal.as_ref(), DecimalFormat::default())
+	}
+
+	fn dedecimalify_with<D>(decimal: D, format: DecimalFormat) -> Result<Self>
+	where
+		D: AsRef<[u8]>,
+	{
+		dedecimalify(decimal.as_ref(), format.check()?)
+	}
+}
+#[test]
+fn dedecimalify_should_work() {
+	let with = |separator, decimals| DecimalFormat { separator, decimals, ..Default::default() };
+
+	assert_eq!(<[u8; 0]>::dedecimalify("0"), Ok([]));
+	assert_eq!(<[u8; 1]>::dedecimalify("00255"), Ok([255]));
+	assert_eq!(<[u8; 16]>::dedecimalify("340282366920938463463374607431768211455"), Ok([0xff; 16]));
+	assert_eq!(
+		<[u8; 32]>::dedecimalify(
+			"115792089237316195423570985008687907853269984665640564039457584007913129639935"
+		),
+		Ok([0xff; 32])
+	);
+	assert_eq!(
+		<[u8; 8]>::dedecimalify_with(
+			"1000000000",
+			DecimalFormat { endianness: Endianness::Little, ..Default::default() }
+		),
+		Ok(1_000_000_000_u64.to_le_bytes())
+	);
+	// Separator.
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1,000", with(Some(','), 0)),
+		Ok(1_000_u32.to_be_bytes())
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1000", with(Some(','), 0)),
+		Ok(1_000_u32.to_be_bytes())
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("123\u{202f}456\u{202f}789", with(Some('\u{202f}'), 0)),
+		Ok(123_456_789_u32.to_be_bytes())
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with(",100", with(Some(','), 0)),
+		Err(Error::InvalidCharacter { character: ',', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1000,000", with(Some(','), 0)),
+		Err(Error::InvalidCharacter { character: ',', index: 4, char_index: 4 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1,000,00", with(Some(','), 0)),
+		Err(Error::InvalidCharacter { character: ',', index: 5, char_index: 5 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1,,000", with(Some(','), 0)),
+		Err(Error::InvalidCharacter { character: ',', index: 1, char_index: 1 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify("1,000"),
+		Err(Error::InvalidCharacter { character: ',', index: 1, char_index: 1 })
+	);
+	// Decimals.
+	assert_eq!(<[u8; 4]>::dedecimalify_with("0.005", with(None, 3)), Ok(5_u32.to_be_bytes()));
+	assert_eq!(<[u8; 4]>::dedecimalify_with("1", with(None, 3)), Ok(1_000_u32.to_be_bytes()));
+	assert_eq!(
+		<[u8; 16]>::dedecimalify_with("1,000,000,000,000.0", with(Some(','), 18)),
+		Ok(10_u128.pow(30).to_be_bytes())
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("0.0051", with(None, 3)),
+		Err(Error::InvalidCharacter { character: '1', index: 5, char_index: 5 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1.", with(None, 3)),
+		Err(Error::InvalidCharacter { character: '.', index: 1, char_index: 1 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with(".5", with(None, 3)),
+		Err(Error::InvalidCharacter { character: '.', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify("1.5"),
+		Err(Error::InvalidCharacter { character: '.', index: 1, char_index: 1 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1.000,5", with(Some(','), 3)),
+		Err(Error::InvalidCharacter { character: ',', index: 5, char_index: 5 })
+	);
+	// Characters.
+	assert_eq!(
+		<[u8; 4]>::dedecimalify(""),
+		Err(Error::InsufficientLength { min: 1, actual: 0, unit: LengthUnit::Character })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify("-1"),
+		Err(Error::InvalidCharacter { character: '-', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify("12我"),
+		Err(Error::InvalidCharacter { character: '我', index: 2, char_index: 2 })
+	);
+	assert_eq!(<[u8; 4]>::dedecimalify(b"1\xff"), Err(Error::InvalidByte { byte: 0xff, index: 1 }));
+	// Overflow.
+	assert_eq!(
+		<[u8; 0]>::dedecimalify("1"),
+		Err(Error::CapacityOverflow { capacity: 0, length: 1 })
+	);
+	assert_eq!(
+		<[u8; 16]>::dedecimalify("340282366920938463463374607431768211456"),
+		Err(Error::CapacityOverflow { capacity: 16, length: 17 })
+	);
+	assert_eq!(
+		<[u8; 1]>::dedecimalify_with("2.56", with(None, 2)),
+		Err(Error::CapacityOverflow { capacity: 1, length: 2 })
+	);
+	assert_eq!(
+		<[u8; 32]>::dedecimalify_with("1", with(None, 255)),
+		Err(Error::CapacityOverflow { capacity: 32, length: 106 })
+	);
+	// Format.
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("15000", with(Some('5'), 0)),
+		Err(Error::InvalidThousandsSeparator { separator: '5' })
+	);
+	assert_eq!(
+		<[u8; 4]>::dedecimalify_with("1.000", with(Some('.'), 0)),
+		Err(Error::InvalidThousandsSeparator { separator: '.' })
+	);
+	assert_eq!(
+		<[u8; 32]>::dedecimalify_with("1", with(None, 256)),
+		Err(Error::InvalidDecimals { decimals: 256, max: 255 })
+	);
+	assert_eq!(
+		<[u8; 32]>::dedecimalify_with("1", with(None, usize::MAX)),
+		Err(Error::InvalidDecimals { decimals: usize::MAX, max: 255 })
+	);
+}
+
+fn decimalify(bytes: &[u8], format: DecimalFormat) -> String {
+	// The bytes in big-endian, divided by `CHUNK` each round.
+	let mut value = match format.endianness {
+		Endianness::Big => bytes.to_vec(),
+		Endianness::Little => bytes.iter().rev().copied().collect(),
+	};
+	// The digits in little-endian.
+	let mut digits = Vec::with_capacity(bytes.len() * 241 / 100 + CHUNK_DIGITS);
+	let mut start = value.iter().take_while(|b| **b == 0).count();
+
+	while start < value.len() {
+		let mut rem = 0_u64;
+
+		for byte in &mut value[start..] {
+			rem = rem << 8 | *byte as u64;
+			*byte = (rem / CHUNK) as u8;
+			rem %= CHUNK;
+		}
+		for _ in 0..CHUNK_DIGITS {
+			digits.push(b'0' + (rem % 10) as u8);
+
+			rem /= 10;
+		}
+
+		start += value[start..].iter().take_while(|b| **b == 0).count();
+	}
+
+	let len = digits.iter().rposition(|d| *d != b'0').map_or(0, |i| i + 1).max(format.decimals + 1);
+
+	digits.resize(len, b'0');
+
+	let mut decimal = String::with_capacity(len + len / 3 * 4 + 1);
+	let int_len = len - format.decimals;
+
+	for (i, digit) in digits.iter().rev().enumerate() {
+		if i == int_len {
+			decimal.push('.');
+		} else if let Some(separator) = format.separator
+			&& i != 0 && i < int_len
+			&& (int_len - i).is_multiple_of(3)
+		{
+			decimal.push(separator);
+		}
+
+		decimal.push(*digit as _);
+	}
+
+	decimal
+}
+
+fn dedecimalify<const N: usize>(decimal: &[u8], format: DecimalFormat) -> Result<[u8; N]> {
+	if decimal.is_empty() {
+		Err(Error::InsufficientLength { min: 1, actual: 0, unit: LengthUnit::Character })?;
+	}
+
+	let mut separator = [0; 4];
+	let separator = format.separator.map(|c| c.encode_utf8(&mut separator).as_bytes());
+	let point = if format.decimals == 0 { None } else { decimal.iter().position(|c| *c == b'.') };
+	let int_end = point.unwrap_or(decimal.len());
+	let invalid = |i: usize| {
+		// The characters before `i` are all checked, they are ASCII or the separator; qed.
+		Error::invalid_character(&decimal[i..], i, i)
+	};
+	// The digits of the integer part then the fractional part.
+	let mut digits = Vec::with_capacity(decimal.len() + format.decimals);
+	// The integer digits before the first separator and whether there is one.
+	let (mut group, mut grouped) = (0, false);
+	let mut i = 0;
+
+	while i < int_end {
+		let c = decimal[i];
+
+		if c.is_ascii_digit() {
+			digits.push(c - b'0');
+
+			group += 1;
+			i += 1;
+		} else if let Some(separator) = separator
+			&& decimal[i..int_end].starts_with(separator)
+		{
+			// Each separator is followed by exactly 3 digits, which also checks the groups after
+			// the first one.
+			let next = decimal[i + separator.len()..int_end]
+				.iter()
+				.take_while(|c| c.is_ascii_digit())
+				.count();
+
+			if !(grouped || (1..=3).contains(&group)) || next != 3 {
+				Err(invalid(i))?;
+			}
+
+			grouped = true;
+			i += separator.len();
+		} else {
+			Err(invalid(i))?;
+		}
+	}
+
+	let int_digits = digits.len();
+
+	if let Some(point) = point {
+		let fraction = &decimal[point + 1..];
+
+		if point == 0 || fraction.is_empty() {
+			Err(invalid(point))?;
+		}
+
+		for (j, c) in fraction.iter().enumerate() {
+			if !c.is_ascii_digit() || j >= format.decimals {
+				Err(invalid(point + 1 + j))?;
+			}
+
+			digits.push(c - b'0');
+		}
+	}
+
+	digits.resize(int_digits + format.decimals, 0);
+
+	let bytes = to_bytes(&digits);
+
+	if bytes.len() > N {
+		Err(Error::CapacityOverflow { capacity: N, length: bytes.len() })?;
+	}
+
+	let mut array = [0; N];
+
+	match format.endianness {
+		Endianness::Big => array[N - bytes.len()..].copy_from_slice(&bytes),
+		Endianness::Little =>
+			array[..bytes.len()].iter_mut().zip(bytes.iter().rev()).for_each(|(a, b)| *a = *b),
+	}
+
+	Ok(array)
+}
+
+// Convert the decimal digits to the minimal big-endian bytes.
+fn to_bytes(digits: &[u8]) -> Vec<u8> {
+	// The bytes in little-endian.
+	let mut bytes = Vec::<u8>::with_capacity(digits.len() * 42 / 100 + 1);
+
+	for chunk in digits.chunks(CHUNK_DIGITS) {
+		let (mul, add) =
+			chunk.iter().fold((1_u64, 0_u64), |(m, a), d| (m * 10, a * 10 + *d as u64));
+		let mut carry = add;
+
+		for byte in &mut bytes {
+			carry += *byte as u64 * mul;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.push(carry as u8);
+
+			carry >>= 8;
+		}
+	}
+
+	bytes.reverse();
+
+	bytes
+}
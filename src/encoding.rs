@@ -0,0 +1,198 @@
+//! The text encodings behind a common interface.
+//!
+//! Each encoding is a marker type implementing [`Encoding`], the generic helpers such as
+//! `as_encoded` work with any of them, including the ones defined by other crates.
+
+// self
+use crate::{
+	Base32Variant, Base32ify, Base58ify, Base64Variant, Base64ify, Debase32ify, Debase58ify,
+	Debase64ify, Dehexify, Hexify, prelude::*,
+};
+
+/// A text encoding of bytes.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{
+/// 	Dehexify, Error, Hexify, Result,
+/// 	encoding::{Encoding, Hex},
+/// };
+///
+/// assert_eq!(Hex::encode(b"Love"), "4c6f7665");
+/// assert_eq!(Hex::decode(b"0x4c6f7665"), Ok(b"Love".to_vec()));
+///
+/// // A custom encoding.
+/// struct HexPrefixedUpperEncoding;
+/// impl Encoding for HexPrefixedUpperEncoding {
+/// 	fn encode(bytes: &[u8]) -> String {
+/// 		bytes.hexify_prefixed_upper()
+/// 	}
+///
+/// 	fn encoded_len(len: usize) -> usize {
+/// 		2 + len * 2
+/// 	}
+///
+/// 	fn decode(encoded: &[u8]) -> Result<Vec<u8>> {
+/// 		<Vec<u8>>::dehexify_upper(encoded)
+/// 	}
+/// }
+///
+/// let mut bytes = [0; 4];
+///
+/// assert_eq!(HexPrefixedUpperEncoding::encode(b"Love"), "0x4C6F7665");
+/// assert_eq!(
+/// 	HexPrefixedUpperEncoding::decode_slice_mut(b"0x4C6F7665", &mut bytes),
+/// 	Ok(b"Love".as_slice())
+/// );
+/// assert_eq!(
+/// 	HexPrefixedUpperEncoding::decode(b"0x4c6f7665"),
+/// 	Err(Error::InvalidCharacter { character: 'c', index: 3, char_index: 3 })
+/// );
+/// ```
+pub trait Encoding {
+	/// Encode the bytes.
+	fn encode(bytes: &[u8]) -> String;
+
+	/// Return the length of the encoded bytes, or its upper bound if it depends on the bytes.
+	fn encoded_len(len: usize) -> usize;
+
+	/// Decode the bytes from the encoded.
+	fn decode(encoded: &[u8]) -> Result<Vec<u8>>;
+
+	/// Decode the bytes from the encoded into a mutable slice source.
+	///
+	/// The slice must be exactly as long as the decoded bytes. The default implementation decodes
+	/// to a `Vec<u8>` then copies it.
+	fn decode_slice_mut<'a>(encoded: &[u8], slice_src: &'a mut [u8]) -> Result<&'a [u8]> {
+		let bytes = Self::decode(encoded)?;
+
+		if bytes.len() != slice_src.len() {
+			Err(Error::MismatchedLength {
+				expect: slice_src.len(),
+				actual: bytes.len(),
+				unit: LengthUnit::Byte,
+			})?;
+		}
+
+		slice_src.copy_from_slice(&bytes);
+
+		Ok(slice_src)
+	}
+}
+
+/// Lowercase hex, the decoding accepts both cases and the `0x` prefix as [`Dehexify`] does.
+#[derive(Clone, Copy, Debug)]
+pub struct Hex;
+impl Encoding for Hex {
+	fn encode(bytes: &[u8]) -> String {
+		bytes.hexify()
+	}
+
+	fn encoded_len(len: usize) -> usize {
+		len * 2
+	}
+
+	fn decode(encoded: &[u8]) -> Result<Vec<u8>> {
+		<Vec<u8>>::dehexify(encoded)
+	}
+
+	fn decode_slice_mut<'a>(encoded: &[u8], slice_src: &'a mut [u8]) -> Result<&'a [u8]> {
+		crate::dehexify_slice_mut(encoded, slice_src)
+	}
+}
+
+/// RFC 4648 Base32 with the standard alphabet and padding.
+#[derive(Clone, Copy, Debug)]
+pub struct Base32;
+impl Encoding for Base32 {
+	fn encode(bytes: &[u8]) -> String {
+		bytes.base32ify()
+	}
+
+	fn encoded_len(len: usize) -> usize {
+		len.div_ceil(5) * 8
+	}
+
+	fn decode(encoded: &[u8]) -> Result<Vec<u8>> {
+		<Vec<u8>>::debase32ify(encoded)
+	}
+
+	fn decode_slice_mut<'a>(encoded: &[u8], slice_src: &'a mut [u8]) -> Result<&'a [u8]> {
+		crate::debase32ify_slice_mut(encoded, slice_src, Base32Variant::Standard)
+	}
+}
+
+/// Base58 with Bitcoin's alphabet.
+#[derive(Clone, Copy, Debug)]
+pub struct Base58;
+impl Encoding for Base58 {
+	fn encode(bytes: &[u8]) -> String {
+		bytes.base58ify()
+	}
+
+	// `log(256) / log(58)` is about `1.37`.
+	fn encoded_len(len: usize) -> usize {
+		len * 138 / 100 + 1
+	}
+
+	fn decode(encoded: &[u8]) -> Result<Vec<u8>> {
+		<Vec<u8>>::debase58ify(encoded)
+	}
+}
+
+/// RFC 4648 Base64 with the standard alphabet and padding.
+#[derive(Clone, Copy, Debug)]
+pub struct Base64;
+impl Encoding for Base64 {
+	fn encode(bytes: &[u8]) -> String {
+		bytes.base64ify()
+	}
+
+	fn encoded_len(len: usize) -> usize {
+		len.div_ceil(3) * 4
+	}
+
+	fn decode(encoded: &[u8]) -> Result<Vec<u8>> {
+		<Vec<u8>>::debase64ify(encoded)
+	}
+
+	fn decode_slice_mut<'a>(encoded: &[u8], slice_src: &'a mut [u8]) -> Result<&'a [u8]> {
+		crate::debase64ify_slice_mut(encoded, slice_src, Base64Variant::Standard)
+	}
+}
+
+#[test]
+fn encoding_should_work() {
+	fn check<E>(bytes: &[u8])
+	where
+		E: Encoding,
+	{
+		let encoded = E::encode(bytes);
+		let mut slice = alloc::vec![0; bytes.len()];
+
+		assert!(encoded.len() <= E::encoded_len(bytes.len()));
+		assert_eq!(E::decode(encoded.as_bytes()).as_deref(), Ok(bytes));
+		assert_eq!(E::decode_slice_mut(encoded.as_bytes(), &mut slice), Ok(bytes));
+		assert_eq!(
+			E::decode_slice_mut(encoded.as_bytes(), &mut [0; 100]),
+			Err(Error::MismatchedLength {
+				expect: 100,
+				actual: bytes.len(),
+				unit: LengthUnit::Byte
+			})
+		);
+	}
+
+	for bytes in [b"".as_slice(), b"L", b"Love", b"Love Jane Forever", &[0; 5], &[0xff; 32]] {
+		check::<Hex>(bytes);
+		check::<Base32>(bytes);
+		check::<Base58>(bytes);
+		check::<Base64>(bytes);
+	}
+
+	assert_eq!(Hex::encoded_len(4), 8);
+	assert_eq!(Base32::encoded_len(4), 8);
+	assert_eq!(Base64::encoded_len(4), 8);
+	assert_eq!(Base58::encode(&[0xff; 32]).len(), 44);
+	assert_eq!(Base58::encoded_len(32), 45);
+}
@@ -0,0 +1,203 @@
+// core
+use core::fmt::Write;
+// self
+use crate::{HEX2DIGIT, prelude::*};
+
+/// Escapify `Self` to a string which round-trips the bytes exactly.
+///
+/// The valid UTF-8 is kept as is, except `\`, `\t`, `\n`, `\r` and the other control characters.
+/// These and the invalid UTF-8 bytes are escaped as in a Rust byte string literal, the bytes in
+/// lowercase `\xNN`.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Escapify;
+///
+/// // `[u8; N]`.
+/// assert_eq!(b"Love\tJane\n".escapify(), "Love\\tJane\\n");
+/// // `&[u8]`.
+/// assert_eq!(b"\xe6\x88\x91\xff\0".as_slice().escapify(), "我\\xff\\x00");
+/// // `Vec<u8>`.
+/// assert_eq!(b"C:\\Jane".to_vec().escapify(), "C:\\\\Jane");
+/// ```
+pub trait Escapify {
+	/// Escapify `Self`.
+	fn escapify(&self) -> String;
+}
+macro_rules! escapify_bytes_fns {
+	() => {
+		fn escapify(&self) -> String {
+			escapify(self)
+		}
+	};
+}
+impl<const N: usize> Escapify for [u8; N] {
+	escapify_bytes_fns! {}
+}
+impl Escapify for [u8] {
+	escapify_bytes_fns! {}
+}
+impl Escapify for Vec<u8> {
+	escapify_bytes_fns! {}
+}
+#[test]
+fn escapify_should_work() {
+	assert_eq!([].escapify(), "");
+	assert_eq!(b"Love Jane Forever".escapify(), "Love Jane Forever");
+	assert_eq!(b"'\"".escapify(), "'\"");
+	assert_eq!(b"\\\t\n\r\0\x1b\x7f".escapify(), "\\\\\\t\\n\\r\\x00\\x1b\\x7f");
+	assert_eq!("我爱你".as_bytes().escapify(), "我爱你");
+	// U+0085, a control character of 2 bytes.
+	assert_eq!("\u{85}".as_bytes().escapify(), "\\xc2\\x85");
+	// Truncated and invalid UTF-8.
+	assert_eq!(b"\xe6\x88".escapify(), "\\xe6\\x88");
+	assert_eq!(b"\xe6\x88Love\xff".to_vec().escapify(), "\\xe6\\x88Love\\xff");
+}
+
+/// Deescapify the given string to `Self`.
+///
+/// Besides the escapes produced by [`Escapify`], `\'`, `\"` and `\0` are accepted, and `\xNN` is
+/// case-insensitive. An unknown or incomplete escape is reported with [`Error::InvalidEscape`] at
+/// its `\`.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Deescapify, Error};
+///
+/// // `[u8; N]`.
+/// assert_eq!(<[u8; 5]>::deescapify("Love\\n"), Ok(*b"Love\n"));
+/// // `Vec<u8>`.
+/// assert_eq!(<Vec<u8>>::deescapify("我\\xFF\\0"), Ok(b"\xe6\x88\x91\xff\0".to_vec()));
+/// assert_eq!(<Vec<u8>>::deescapify("Love\\q"), Err(Error::InvalidEscape { index: 4 }));
+/// ```
+pub trait Deescapify
+where
+	Self: Sized,
+{
+	/// Deescapify `Self`.
+	fn deescapify<E>(escaped: E) -> Result<Self>
+	where
+		E: AsRef<[u8]>;
+}
+impl<const N: usize> Deescapify for [u8; N] {
+	fn deescapify<E>(escaped: E) -> Result<Self>
+	where
+		E: AsRef<[u8]>,
+	{
+		let bytes = deescapify(escaped.as_ref())?;
+
+		bytes.try_into().map_err(|bytes: Vec<u8>| Error::MismatchedLength {
+			expect: N,
+			actual: bytes.len(),
+			unit: LengthUnit::Byte,
+		})
+	}
+}
+impl Deescapify for Vec<u8> {
+	fn deescapify<E>(escaped: E) -> Result<Self>
+	where
+		E: AsRef<[u8]>,
+	{
+		deescapify(escaped.as_ref())
+	}
+}
+#[test]
+fn deescapify_should_work() {
+	assert_eq!(<Vec<u8>>::deescapify(""), Ok(Vec::new()));
+	assert_eq!(<Vec<u8>>::deescapify("Love Jane Forever"), Ok(b"Love Jane Forever".to_vec()));
+	assert_eq!(<Vec<u8>>::deescapify("'\""), Ok(b"'\"".to_vec()));
+	assert_eq!(<Vec<u8>>::deescapify("\\'\\\""), Ok(b"'\"".to_vec()));
+	assert_eq!(
+		<Vec<u8>>::deescapify("\\\\\\t\\n\\r\\0\\x1b\\x7F"),
+		Ok(b"\\\t\n\r\0\x1b\x7f".to_vec())
+	);
+	assert_eq!(<Vec<u8>>::deescapify("我爱你"), Ok("我爱你".as_bytes().to_vec()));
+	assert_eq!(<Vec<u8>>::deescapify("\\xe6\\x88\\x91"), Ok("我".as_bytes().to_vec()));
+	assert_eq!(<[u8; 4]>::deescapify("\\xe6\\x88Lo"), Ok(*b"\xe6\x88Lo"));
+
+	// Round trip.
+	for bytes in [b"".as_slice(), b"\xe6\x88Love\xff", "\u{85}我\\".as_bytes(), &[0xff; 32]] {
+		assert_eq!(<Vec<u8>>::deescapify(bytes.escapify()).as_deref(), Ok(bytes));
+	}
+	for byte in 0..=u8::MAX {
+		assert_eq!(<[u8; 1]>::deescapify([byte].escapify()), Ok([byte]));
+	}
+
+	// Escapes.
+	assert_eq!(<Vec<u8>>::deescapify("\\"), Err(Error::InvalidEscape { index: 0 }));
+	assert_eq!(<Vec<u8>>::deescapify("Love\\q"), Err(Error::InvalidEscape { index: 4 }));
+	assert_eq!(<Vec<u8>>::deescapify("Love\\x"), Err(Error::InvalidEscape { index: 4 }));
+	assert_eq!(<Vec<u8>>::deescapify("\\x4"), Err(Error::InvalidEscape { index: 0 }));
+	assert_eq!(<Vec<u8>>::deescapify("L\\x4g"), Err(Error::InvalidEscape { index: 1 }));
+	assert_eq!(<Vec<u8>>::deescapify("\\\\\\u{0}"), Err(Error::InvalidEscape { index: 2 }));
+
+	// Length.
+	assert_eq!(
+		<[u8; 4]>::deescapify("Love\\n"),
+		Err(Error::MismatchedLength { expect: 4, actual: 5, unit: LengthUnit::Byte })
+	);
+}
+
+fn escapify(bytes: &[u8]) -> String {
+	let mut escaped = String::with_capacity(bytes.len());
+	let escape_byte = |escaped: &mut String, byte: u8| {
+		write!(escaped, "\\x{byte:02x}").expect("writing to a `String` never fails; qed");
+	};
+
+	for chunk in bytes.utf8_chunks() {
+		for c in chunk.valid().chars() {
+			match c {
+				'\\' => escaped.push_str("\\\\"),
+				'\t' => escaped.push_str("\\t"),
+				'\n' => escaped.push_str("\\n"),
+				'\r' => escaped.push_str("\\r"),
+				c if c.is_control() =>
+					c.encode_utf8(&mut [0; 4]).bytes().for_each(|b| escape_byte(&mut escaped, b)),
+				c => escaped.push(c),
+			}
+		}
+
+		chunk.invalid().iter().for_each(|b| escape_byte(&mut escaped, *b));
+	}
+
+	escaped
+}
+
+fn deescapify(escaped: &[u8]) -> Result<Vec<u8>> {
+	let mut bytes = Vec::with_capacity(escaped.len());
+	let mut i = 0;
+
+	while i < escaped.len() {
+		if escaped[i] != b'\\' {
+			bytes.push(escaped[i]);
+
+			i += 1;
+
+			continue;
+		}
+
+		let (byte, len) = match escaped.get(i + 1) {
+			Some(b'\\') => (b'\\', 2),
+			Some(b'\'') => (b'\'', 2),
+			Some(b'"') => (b'"', 2),
+			Some(b'0') => (b'\0', 2),
+			Some(b't') => (b'\t', 2),
+			Some(b'n') => (b'\n', 2),
+			Some(b'r') => (b'\r', 2),
+			Some(b'x') => match escaped.get(i + 2..i + 4) {
+				Some([h, l]) => match (HEX2DIGIT[*h as usize], HEX2DIGIT[*l as usize]) {
+					(Some(h), Some(l)) => (h << 4 | l, 4),
+					_ => Err(Error::InvalidEscape { index: i })?,
+				},
+				_ => Err(Error::InvalidEscape { index: i })?,
+			},
+			_ => Err(Error::InvalidEscape { index: i })?,
+		};
+
+		bytes.push(byte);
+
+		i += len;
+	}
+
+	Ok(bytes)
+}
@@ -0,0 +1,249 @@
+//! The hash functions behind the checksums.
+
+const SHA256_K: [u32; 64] = [
+	0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+	0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+	0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+	0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+	0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+	0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+	0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+	0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Hash the data with SHA-256.
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+	let mut state = [
+		0x6a09e667_u32,
+		0xbb67ae85,
+		0x3c6ef372,
+		0xa54ff53a,
+		0x510e527f,
+		0x9b05688c,
+		0x1f83d9ab,
+		0x5be0cd19,
+	];
+	let (blocks, rest) = data.as_chunks::<64>();
+
+	for block in blocks {
+		sha256_compress(&mut state, block);
+	}
+
+	// Pad with `0x80`, zeros and the length in bits, into one or two blocks.
+	let mut tail = [0; 128];
+	let len = if rest.len() < 56 { 64 } else { 128 };
+
+	tail[..rest.len()].copy_from_slice(rest);
+	tail[rest.len()] = 0x80;
+	tail[len - 8..len].copy_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+
+	for block in tail[..len].as_chunks::<64>().0 {
+		sha256_compress(&mut state, block);
+	}
+
+	let mut hash = [0; 32];
+
+	for (bytes, word) in hash.as_chunks_mut::<4>().0.iter_mut().zip(state) {
+		*bytes = word.to_be_bytes();
+	}
+
+	hash
+}
+#[test]
+fn sha256_should_work() {
+	use crate::Hexify;
+
+	assert_eq!(
+		sha256(b"").hexify(),
+		"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+	);
+	assert_eq!(
+		sha256(b"abc").hexify(),
+		"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+	);
+	assert_eq!(
+		sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").hexify(),
+		"248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+	);
+	assert_eq!(
+		sha256(&[b'a'; 1_000]).hexify(),
+		"41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+	);
+}
+
+/// Hash the data with SHA-256 twice.
+pub(crate) fn sha256d(data: &[u8]) -> [u8; 32] {
+	sha256(&sha256(data))
+}
+
+fn sha256_compress(state: &mut [u32; 8], block: &[u8; 64]) {
+	let mut w = [0; 64];
+
+	for (w, bytes) in w.iter_mut().zip(block.as_chunks::<4>().0) {
+		*w = u32::from_be_bytes(*bytes);
+	}
+	for i in 16..64 {
+		let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+		let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+
+		w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+	}
+
+	let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+	for i in 0..64 {
+		let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+		let ch = (e & f) ^ (!e & g);
+		let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA256_K[i]).wrapping_add(w[i]);
+		let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+		let maj = (a & b) ^ (a & c) ^ (b & c);
+		let t2 = s0.wrapping_add(maj);
+
+		h = g;
+		g = f;
+		f = e;
+		e = d.wrapping_add(t1);
+		d = c;
+		c = b;
+		b = a;
+		a = t1.wrapping_add(t2);
+	}
+
+	for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+		*s = s.wrapping_add(v);
+	}
+}
+
+const BLAKE2B_IV: [u64; 8] = [
+	0x6a09e667f3bcc908,
+	0xbb67ae8584caa73b,
+	0x3c6ef372fe94f82b,
+	0xa54ff53a5f1d36f1,
+	0x510e527fade682d1,
+	0x9b05688c2b3e6c1f,
+	0x1f83d9abfb41bd6b,
+	0x5be0cd19137e2179,
+];
+const BLAKE2B_SIGMA: [[usize; 16]; 12] = [
+	[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+	[14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+	[11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+	[7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+	[9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+	[2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+	[12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+	[13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+	[6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+	[10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+	[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+	[14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+/// Hash the concatenation of the data with Blake2b-512.
+pub(crate) fn blake2b_512(data: &[&[u8]]) -> [u8; 64] {
+	let mut state = BLAKE2B_IV;
+	let mut block = [0; 128];
+	let (mut filled, mut counter) = (0, 0_u128);
+
+	// The parameter block: 64 bytes digest, no key, fanout and depth of 1.
+	state[0] ^= 0x0101_0040;
+
+	for mut data in data.iter().copied() {
+		while !data.is_empty() {
+			// The last block must be compressed with the final flag, so never compress a full block
+			// until there is more data.
+			if filled == 128 {
+				counter += 128;
+
+				blake2b_compress(&mut state, &block, counter, false);
+
+				filled = 0;
+			}
+
+			let len = data.len().min(128 - filled);
+
+			block[filled..filled + len].copy_from_slice(&data[..len]);
+
+			filled += len;
+			data = &data[len..];
+		}
+	}
+
+	block[filled..].fill(0);
+	blake2b_compress(&mut state, &block, counter + filled as u128, true);
+
+	let mut hash = [0; 64];
+
+	for (bytes, word) in hash.as_chunks_mut::<8>().0.iter_mut().zip(state) {
+		*bytes = word.to_le_bytes();
+	}
+
+	hash
+}
+#[test]
+fn blake2b_512_should_work() {
+	use crate::Hexify;
+
+	assert_eq!(
+		blake2b_512(&[]).hexify(),
+		"786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419\
+		d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"
+	);
+	assert_eq!(
+		blake2b_512(&[b"a", b"bc"]).hexify(),
+		"ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+		7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+	);
+	assert_eq!(blake2b_512(&[&[b'a'; 128]]), blake2b_512(&[&[b'a'; 100], &[b'a'; 28]]));
+	assert_eq!(
+		blake2b_512(&[&[b'a'; 1_000]]).hexify(),
+		"d6a69459fe93fc6b9537ed4336e5099e0dcca3e97290a412500ed7a0daffb03d\
+		80cf3650a20e0591f748e10c3c534945ee83d5f2c9722f1a68d98b8c01af23fd"
+	);
+}
+
+fn blake2b_compress(state: &mut [u64; 8], block: &[u8; 128], counter: u128, last: bool) {
+	let mut m = [0; 16];
+
+	for (m, bytes) in m.iter_mut().zip(block.as_chunks::<8>().0) {
+		*m = u64::from_le_bytes(*bytes);
+	}
+
+	let mut v = [0; 16];
+
+	v[..8].copy_from_slice(state);
+	v[8..].copy_from_slice(&BLAKE2B_IV);
+	v[12] ^= counter as u64;
+	v[13] ^= (counter >> 64) as u64;
+
+	if last {
+		v[14] = !v[14];
+	}
+
+	for s in &BLAKE2B_SIGMA {
+		blake2b_g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+		blake2b_g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+		blake2b_g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+		blake2b_g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+		blake2b_g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+		blake2b_g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+		blake2b_g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+		blake2b_g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+	}
+
+	for (i, s) in state.iter_mut().enumerate() {
+		*s ^= v[i] ^ v[i + 8];
+	}
+}
+
+#[inline(always)]
+fn blake2b_g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+	v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+	v[d] = (v[d] ^ v[a]).rotate_right(32);
+	v[c] = v[c].wrapping_add(v[d]);
+	v[b] = (v[b] ^ v[c]).rotate_right(24);
+	v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+	v[d] = (v[d] ^ v[a]).rotate_right(16);
+	v[c] = v[c].wrapping_add(v[d]);
+	v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
@@ -4,6 +4,21 @@ pub use hexify::*;
 mod dehexify;
 pub use dehexify::*;
 
+mod validate;
+pub use validate::*;
+
+mod list;
+pub use list::*;
+
+mod record;
+pub use record::*;
+
+mod wrapper;
+pub use wrapper::*;
+
+#[cfg(feature = "rayon")] mod par;
+#[cfg(feature = "rayon")] pub use par::*;
+
 // self
 #[cfg(test)] use crate::prelude::*;
 
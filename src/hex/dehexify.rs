@@ -1,9 +1,16 @@
 // core
-use core::str;
+use core::{mem::MaybeUninit, num::ParseIntError, ptr, str};
+// alloc
+#[cfg(target_has_atomic = "ptr")] use alloc::sync::Arc;
+use alloc::{borrow::Cow, boxed::Box, rc::Rc};
 // self
 use crate::prelude::*;
 
-static HEX2DIGIT: [Option<u8>; 256] = {
+pub(crate) static HEX2DIGIT: [Option<u8>; 256] = hex2digit(true, true);
+static HEX2DIGIT_LOWER: [Option<u8>; 256] = hex2digit(true, false);
+static HEX2DIGIT_UPPER: [Option<u8>; 256] = hex2digit(false, true);
+
+const fn hex2digit(lower: bool, upper: bool) -> [Option<u8>; 256] {
 	let mut table = [None; 256];
 	let mut i = 0;
 
@@ -16,20 +23,24 @@ static HEX2DIGIT: [Option<u8>; 256] = {
 	i = 0;
 
 	while i <= 5 {
-		table[b'a' as usize + i] = Some((10 + i) as u8);
-		table[b'A' as usize + i] = Some((10 + i) as u8);
+		if lower {
+			table[b'a' as usize + i] = Some((10 + i) as u8);
+		}
+		if upper {
+			table[b'A' as usize + i] = Some((10 + i) as u8);
+		}
 
 		i += 1;
 	}
 
 	table
-};
+}
 
 /// Dehexify the given hex to `Self`.
 ///
 /// # Examples
 /// ```
-/// use array_bytes::{Dehexify, Error};
+/// use array_bytes::{Dehexify, Error, LengthUnit};
 /// use smallvec::SmallVec;
 ///
 /// // Unsigned.
@@ -46,11 +57,17 @@ static HEX2DIGIT: [Option<u8>; 256] = {
 /// );
 /// // SmallVec.
 /// assert_eq!(
-/// 	SmallVec::dehexify("0x4c6f7665204a616e6520466f7265766572").unwrap().into_vec(),
+/// 	<SmallVec<[u8; 64]>>::dehexify("0x4c6f7665204a616e6520466f7265766572").unwrap().into_vec(),
 /// 	b"Love Jane Forever".to_vec()
 /// );
-/// assert_eq!(SmallVec::dehexify("我爱你"), Err(Error::InvalidLength));
-/// assert_eq!(SmallVec::dehexify("0x我爱你"), Err(Error::InvalidLength));
+/// assert_eq!(
+/// 	<SmallVec<[u8; 64]>>::dehexify("我爱你"),
+/// 	Err(Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble })
+/// );
+/// assert_eq!(
+/// 	<SmallVec<[u8; 64]>>::dehexify("0x我爱你"),
+/// 	Err(Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble })
+/// );
 /// // Vec.
 /// assert_eq!(
 /// 	<Vec<u8>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
@@ -58,11 +75,20 @@ static HEX2DIGIT: [Option<u8>; 256] = {
 /// );
 /// assert_eq!(
 /// 	<Vec<u8>>::dehexify("我爱你 "),
-/// 	Err(Error::InvalidCharacter { character: 'æ', index: 0 })
+/// 	Err(Error::InvalidCharacter { character: '我', index: 0, char_index: 0 })
 /// );
 /// assert_eq!(
 /// 	<Vec<u8>>::dehexify(" 我爱你"),
-/// 	Err(Error::InvalidCharacter { character: ' ', index: 0 })
+/// 	Err(Error::InvalidCharacter { character: ' ', index: 0, char_index: 0 })
+/// );
+/// // `Box<[u8]>`, `Rc<[u8]>`, `Arc<[u8]>` and `Cow<'static, [u8]>`.
+/// assert_eq!(<Box<[u8]>>::dehexify("0x4c6f7665"), Ok(b"Love".to_vec().into_boxed_slice()));
+/// // Case-enforcing.
+/// assert_eq!(<[u8; 2]>::dehexify_lower("0xabcd"), Ok([0xab, 0xcd]));
+/// assert_eq!(<[u8; 2]>::dehexify_upper("0xABCD"), Ok([0xab, 0xcd]));
+/// assert_eq!(
+/// 	<[u8; 2]>::dehexify_lower("0xabCd"),
+/// 	Err(Error::InvalidCharacter { character: 'C', index: 4, char_index: 4 })
 /// );
 /// ```
 pub trait Dehexify
@@ -73,19 +99,49 @@ where
 	fn dehexify<H>(hex: H) -> Result<Self>
 	where
 		H: AsRef<[u8]>;
+
+	/// Dehexify `Self` from lowercase hex.
+	///
+	/// Uppercase digits are rejected with [`Error::InvalidCharacter`].
+	fn dehexify_lower<H>(hex: H) -> Result<Self>
+	where
+		H: AsRef<[u8]>;
+
+	/// Dehexify `Self` from uppercase hex.
+	///
+	/// Lowercase digits are rejected with [`Error::InvalidCharacter`].
+	fn dehexify_upper<H>(hex: H) -> Result<Self>
+	where
+		H: AsRef<[u8]>;
+}
+macro_rules! dehexify_fns {
+	($f:ident $(, $map:expr)?) => {
+		fn dehexify<H>(hex: H) -> Result<Self>
+		where
+			H: AsRef<[u8]>,
+		{
+			$f(hex, &HEX2DIGIT)$(.map($map))?
+		}
+
+		fn dehexify_lower<H>(hex: H) -> Result<Self>
+		where
+			H: AsRef<[u8]>,
+		{
+			$f(hex, &HEX2DIGIT_LOWER)$(.map($map))?
+		}
+
+		fn dehexify_upper<H>(hex: H) -> Result<Self>
+		where
+			H: AsRef<[u8]>,
+		{
+			$f(hex, &HEX2DIGIT_UPPER)$(.map($map))?
+		}
+	};
 }
 macro_rules! impl_dehexify_for_unsigned {
 	($($t:ty,)+) => {
 		$(impl Dehexify for $t {
-			fn dehexify<H>(hex: H) -> Result<Self>
-			where
-				H: AsRef<[u8]>,
-			{
-				let hex = strip_0x(hex.as_ref());
-				let hex = str::from_utf8(hex).map_err(Error::Utf8Error)?;
-
-				Self::from_str_radix(hex, 16).map_err(Error::ParseIntError)
-			}
+			dehexify_fns! { dehexify_unsigned }
 		})+
 	};
 }
@@ -98,28 +154,34 @@ impl_dehexify_for_unsigned! {
 	u128,
 }
 impl<const N: usize> Dehexify for [u8; N] {
-	fn dehexify<H>(hex: H) -> Result<Self>
-	where
-		H: AsRef<[u8]>,
-	{
-		dehexify_array(hex)
-	}
+	dehexify_fns! { dehexify_array }
 }
-impl Dehexify for SmallVec<[u8; 64]> {
-	fn dehexify<H>(hex: H) -> Result<Self>
-	where
-		H: AsRef<[u8]>,
-	{
-		dehexify_bytes(hex)
-	}
+impl<const N: usize> Dehexify for SmallVec<[u8; N]> {
+	dehexify_fns! { dehexify_bytes }
 }
 impl Dehexify for Vec<u8> {
-	fn dehexify<H>(hex: H) -> Result<Self>
-	where
-		H: AsRef<[u8]>,
-	{
-		dehexify_bytes(hex).map(|sv| sv.into_vec())
-	}
+	dehexify_fns! { dehexify_vec }
+}
+impl Dehexify for Box<[u8]> {
+	dehexify_fns! { dehexify_vec, Into::into }
+}
+impl Dehexify for Rc<[u8]> {
+	dehexify_fns! { dehexify_vec, Into::into }
+}
+#[cfg(target_has_atomic = "ptr")]
+impl Dehexify for Arc<[u8]> {
+	dehexify_fns! { dehexify_vec, Into::into }
+}
+impl Dehexify for Cow<'static, [u8]> {
+	dehexify_fns! { dehexify_vec, Cow::Owned }
+}
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> Dehexify for arrayvec::ArrayVec<u8, N> {
+	dehexify_fns! { dehexify_array_vec }
+}
+#[cfg(feature = "heapless")]
+impl<const N: usize> Dehexify for heapless::Vec<u8, N> {
+	dehexify_fns! { dehexify_heapless_vec }
 }
 #[test]
 fn dehexify_should_work() {
@@ -135,13 +197,28 @@ fn dehexify_should_work() {
 		<[u8; 17]>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
 		Ok(*b"Love Jane Forever")
 	);
+	assert_eq!(<[u8; 65]>::dehexify([b'f'; 130]), Ok([0xff; 65]));
+	assert_eq!(
+		<[u8; 65]>::dehexify([b'f'; 128]),
+		Err(Error::MismatchedLength { expect: 65, actual: 64, unit: LengthUnit::Byte })
+	);
 	// SmallVec.
 	assert_eq!(
-		SmallVec::dehexify("0x4c6f7665204a616e6520466f7265766572").unwrap().into_vec(),
+		<SmallVec<[u8; 17]>>::dehexify("0x4c6f7665204a616e6520466f7265766572").unwrap().into_vec(),
+		b"Love Jane Forever".to_vec()
+	);
+	assert_eq!(
+		<SmallVec<[u8; 64]>>::dehexify("0x4c6f7665204a616e6520466f7265766572").unwrap().into_vec(),
 		b"Love Jane Forever".to_vec()
 	);
-	assert_eq!(SmallVec::dehexify("我爱你"), Err(Error::InvalidLength));
-	assert_eq!(SmallVec::dehexify("0x我爱你"), Err(Error::InvalidLength));
+	assert_eq!(
+		<SmallVec<[u8; 64]>>::dehexify("我爱你"),
+		Err(Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble })
+	);
+	assert_eq!(
+		<SmallVec<[u8; 64]>>::dehexify("0x我爱你"),
+		Err(Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble })
+	);
 	// Vec.
 	assert_eq!(
 		<Vec<u8>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
@@ -149,11 +226,120 @@ fn dehexify_should_work() {
 	);
 	assert_eq!(
 		<Vec<u8>>::dehexify("我爱你 "),
-		Err(Error::InvalidCharacter { character: 'æ', index: 0 })
+		Err(Error::InvalidCharacter { character: '我', index: 0, char_index: 0 })
 	);
 	assert_eq!(
 		<Vec<u8>>::dehexify(" 我爱你"),
-		Err(Error::InvalidCharacter { character: ' ', index: 0 })
+		Err(Error::InvalidCharacter { character: ' ', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		<Vec<u8>>::dehexify(b"00\xe6\x88"),
+		Err(Error::InvalidByte { byte: 0xe6, index: 2 })
+	);
+	// Box.
+	assert_eq!(
+		<Box<[u8]>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
+		Ok(b"Love Jane Forever".to_vec().into_boxed_slice())
+	);
+	// Rc.
+	assert_eq!(
+		<Rc<[u8]>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
+		Ok(Rc::from(b"Love Jane Forever".as_slice()))
+	);
+	// Arc.
+	#[cfg(target_has_atomic = "ptr")]
+	assert_eq!(
+		<Arc<[u8]>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
+		Ok(Arc::from(b"Love Jane Forever".as_slice()))
+	);
+	// Cow.
+	assert_eq!(
+		<Cow<'static, [u8]>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
+		Ok(Cow::Owned(b"Love Jane Forever".to_vec()))
+	);
+}
+#[cfg(feature = "arrayvec")]
+#[test]
+fn dehexify_array_vec_should_work() {
+	assert_eq!(
+		<arrayvec::ArrayVec<u8, 32>>::dehexify("0x4c6f7665204a616e6520466f7265766572")
+			.unwrap()
+			.as_slice(),
+		b"Love Jane Forever"
+	);
+	assert_eq!(
+		<arrayvec::ArrayVec<u8, 16>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
+		Err(Error::CapacityOverflow { capacity: 16, length: 17 })
+	);
+	assert_eq!(
+		<arrayvec::ArrayVec<u8, 16>>::dehexify("0x0"),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
+}
+#[cfg(feature = "heapless")]
+#[test]
+fn dehexify_heapless_vec_should_work() {
+	assert_eq!(
+		<heapless::Vec<u8, 32>>::dehexify("0x4c6f7665204a616e6520466f7265766572")
+			.unwrap()
+			.as_slice(),
+		b"Love Jane Forever"
+	);
+	assert_eq!(
+		<heapless::Vec<u8, 16>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
+		Err(Error::CapacityOverflow { capacity: 16, length: 17 })
+	);
+	assert_eq!(
+		<heapless::Vec<u8, 16>>::dehexify("0x0"),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
+}
+#[test]
+fn dehexify_case_should_work() {
+	// Unsigned.
+	assert_eq!(u32::dehexify_lower("0x4f5da2"), Ok(5_201_314));
+	assert_eq!(u32::dehexify_upper("0x4F5DA2"), Ok(5_201_314));
+	assert_eq!(
+		u32::dehexify_lower("0x4F5DA2"),
+		Err(Error::InvalidCharacter { character: 'F', index: 3, char_index: 3 })
+	);
+	assert_eq!(
+		u32::dehexify_upper("0x4f5da2"),
+		Err(Error::InvalidCharacter { character: 'f', index: 3, char_index: 3 })
+	);
+	assert_eq!(u32::dehexify("+5"), Ok(5));
+	assert_eq!(
+		u32::dehexify_lower("+5"),
+		Err(Error::InvalidCharacter { character: '+', index: 0, char_index: 0 })
+	);
+	// Array.
+	assert_eq!(<[u8; 2]>::dehexify_lower("0xabcd"), Ok([0xab, 0xcd]));
+	assert_eq!(<[u8; 2]>::dehexify_upper("0xABCD"), Ok([0xab, 0xcd]));
+	assert_eq!(
+		<[u8; 2]>::dehexify_lower("abCd"),
+		Err(Error::InvalidCharacter { character: 'C', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		<[u8; 2]>::dehexify_upper("ABcD"),
+		Err(Error::InvalidCharacter { character: 'c', index: 2, char_index: 2 })
+	);
+	// SmallVec.
+	assert_eq!(
+		<SmallVec<[u8; 64]>>::dehexify_lower("0123456789abcdef").unwrap().into_vec(),
+		[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef].to_vec()
+	);
+	assert_eq!(
+		<SmallVec<[u8; 64]>>::dehexify_upper("0123456789abcdef"),
+		Err(Error::InvalidCharacter { character: 'a', index: 10, char_index: 10 })
+	);
+	// Vec.
+	assert_eq!(
+		<Vec<u8>>::dehexify_upper("0123456789ABCDEF"),
+		Ok([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef].to_vec())
+	);
+	assert_eq!(
+		<Vec<u8>>::dehexify_lower("0123456789ABCDEF"),
+		Err(Error::InvalidCharacter { character: 'A', index: 10, char_index: 10 })
 	);
 }
 
@@ -173,23 +359,7 @@ pub fn dehexify_slice_mut<H>(hex: H, slice_src: &mut [u8]) -> Result<&[u8]>
 where
 	H: AsRef<[u8]>,
 {
-	let hex = strip_0x(hex.as_ref());
-
-	if hex.len() % 2 != 0 {
-		Err(Error::InvalidLength)?;
-	}
-
-	let expected_len = hex.len() >> 1;
-
-	if expected_len != slice_src.len() {
-		Err(Error::MismatchedLength { expect: expected_len })?;
-	}
-
-	for (byte, i) in slice_src.iter_mut().zip((0..hex.len()).step_by(2)) {
-		*byte = dehexify_ascii((&hex[i], i), (&hex[i + 1], i + 1))?;
-	}
-
-	Ok(slice_src)
+	dehexify_slice_mut_with(hex, slice_src, &HEX2DIGIT)
 }
 #[test]
 fn dehexify_slice_mut_should_work() {
@@ -221,22 +391,239 @@ fn dehexify_slice_mut_should_work() {
 	);
 	assert_eq!(bytes, *b"Love Jane Forever");
 
-	assert_eq!(dehexify_slice_mut("0", &mut []), Err(Error::InvalidLength));
-	assert_eq!(dehexify_slice_mut("0x0", &mut []), Err(Error::InvalidLength));
+	assert_eq!(
+		dehexify_slice_mut("0", &mut []),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
+	assert_eq!(
+		dehexify_slice_mut("0x0", &mut []),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
 
-	assert_eq!(dehexify_slice_mut("00", &mut []), Err(Error::MismatchedLength { expect: 1 }));
-	assert_eq!(dehexify_slice_mut("0x0001", &mut []), Err(Error::MismatchedLength { expect: 2 }));
+	assert_eq!(
+		dehexify_slice_mut("00", &mut []),
+		Err(Error::MismatchedLength { expect: 0, actual: 1, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		dehexify_slice_mut("0x0001", &mut [0]),
+		Err(Error::MismatchedLength { expect: 1, actual: 2, unit: LengthUnit::Byte })
+	);
 
 	assert_eq!(
 		dehexify_slice_mut("fg", &mut [0]),
-		Err(Error::InvalidCharacter { character: 'g', index: 1 })
+		Err(Error::InvalidCharacter { character: 'g', index: 1, char_index: 1 })
 	);
 	assert_eq!(
 		dehexify_slice_mut("0xyz", &mut [0]),
-		Err(Error::InvalidCharacter { character: 'y', index: 0 })
+		Err(Error::InvalidCharacter { character: 'y', index: 2, char_index: 2 })
 	);
 }
 
+/// Dehexify lowercase hex into a mutable slice source.
+///
+/// Uppercase digits are rejected with [`Error::InvalidCharacter`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::Error;
+///
+/// let mut array = [0; 2];
+///
+/// assert_eq!(
+/// 	array_bytes::dehexify_slice_mut_lower("0xabcd", &mut array),
+/// 	Ok([0xab, 0xcd].as_slice())
+/// );
+/// assert_eq!(
+/// 	array_bytes::dehexify_slice_mut_lower("0xABCD", &mut array),
+/// 	Err(Error::InvalidCharacter { character: 'A', index: 2, char_index: 2 })
+/// );
+/// ```
+pub fn dehexify_slice_mut_lower<H>(hex: H, slice_src: &mut [u8]) -> Result<&[u8]>
+where
+	H: AsRef<[u8]>,
+{
+	dehexify_slice_mut_with(hex, slice_src, &HEX2DIGIT_LOWER)
+}
+#[test]
+fn dehexify_slice_mut_lower_should_work() {
+	let mut bytes = [0; 2];
+	assert_eq!(dehexify_slice_mut_lower("abcd", &mut bytes), Ok([0xab, 0xcd].as_slice()));
+	assert_eq!(
+		dehexify_slice_mut_lower("abcD", &mut bytes),
+		Err(Error::InvalidCharacter { character: 'D', index: 3, char_index: 3 })
+	);
+}
+
+/// Dehexify uppercase hex into a mutable slice source.
+///
+/// Lowercase digits are rejected with [`Error::InvalidCharacter`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::Error;
+///
+/// let mut array = [0; 2];
+///
+/// assert_eq!(
+/// 	array_bytes::dehexify_slice_mut_upper("0xABCD", &mut array),
+/// 	Ok([0xab, 0xcd].as_slice())
+/// );
+/// assert_eq!(
+/// 	array_bytes::dehexify_slice_mut_upper("0xabcd", &mut array),
+/// 	Err(Error::InvalidCharacter { character: 'a', index: 2, char_index: 2 })
+/// );
+/// ```
+pub fn dehexify_slice_mut_upper<H>(hex: H, slice_src: &mut [u8]) -> Result<&[u8]>
+where
+	H: AsRef<[u8]>,
+{
+	dehexify_slice_mut_with(hex, slice_src, &HEX2DIGIT_UPPER)
+}
+#[test]
+fn dehexify_slice_mut_upper_should_work() {
+	let mut bytes = [0; 2];
+	assert_eq!(dehexify_slice_mut_upper("ABCD", &mut bytes), Ok([0xab, 0xcd].as_slice()));
+	assert_eq!(
+		dehexify_slice_mut_upper("ABcD", &mut bytes),
+		Err(Error::InvalidCharacter { character: 'c', index: 2, char_index: 2 })
+	);
+}
+
+/// Dehexify hex into an uninitialized array.
+///
+/// The length is checked before decoding and the digits are written straight into the array,
+/// which makes it suitable for large `N` without any allocation.
+///
+/// # Examples
+/// ```
+/// use core::mem::MaybeUninit;
+///
+/// let mut array = MaybeUninit::<[u8; 17]>::uninit();
+///
+/// assert_eq!(
+/// 	array_bytes::dehexify_array_uninit("0x4c6f7665204a616e6520466f7265766572", &mut array),
+/// 	Ok(&mut { *b"Love Jane Forever" })
+/// );
+/// ```
+pub fn dehexify_array_uninit<H, const N: usize>(
+	hex: H,
+	array: &mut MaybeUninit<[u8; N]>,
+) -> Result<&mut [u8; N]>
+where
+	H: AsRef<[u8]>,
+{
+	dehexify_array_uninit_with(hex, array, &HEX2DIGIT)
+}
+#[test]
+fn dehexify_array_uninit_should_work() {
+	let mut array = MaybeUninit::<[u8; 17]>::uninit();
+	assert_eq!(
+		dehexify_array_uninit("0x4c6f7665204a616e6520466f7265766572", &mut array),
+		Ok(&mut { *b"Love Jane Forever" })
+	);
+
+	let mut array = MaybeUninit::<[u8; 96]>::uninit();
+	assert_eq!(dehexify_array_uninit([b'f'; 192], &mut array), Ok(&mut [0xff; 96]));
+
+	let mut array = MaybeUninit::<[u8; 2]>::uninit();
+	assert_eq!(
+		dehexify_array_uninit("0x0", &mut array),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
+	assert_eq!(
+		dehexify_array_uninit("0x000000", &mut array),
+		Err(Error::MismatchedLength { expect: 2, actual: 3, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		dehexify_array_uninit("0x00zz", &mut array),
+		Err(Error::InvalidCharacter { character: 'z', index: 4, char_index: 4 })
+	);
+}
+
+/// Dehexify the hex in the given buffer in place.
+///
+/// The decoded bytes are written into the front half of the buffer, the returned slice points
+/// to them.
+///
+/// Note that the buffer is left partially overwritten if an error is encountered.
+///
+/// # Examples
+/// ```
+/// let mut buffer = *b"0x4c6f7665204a616e6520466f7265766572";
+///
+/// assert_eq!(
+/// 	array_bytes::dehexify_in_place(&mut buffer),
+/// 	Ok(b"Love Jane Forever".to_vec().as_mut_slice())
+/// );
+/// ```
+pub fn dehexify_in_place(buffer: &mut [u8]) -> Result<&mut [u8]> {
+	let len = dehexify_in_place_inner(buffer)?;
+
+	Ok(&mut buffer[..len])
+}
+#[test]
+fn dehexify_in_place_should_work() {
+	let mut buffer = *b"0x4c6f7665204a616e6520466f7265766572";
+	assert_eq!(dehexify_in_place(&mut buffer).unwrap(), b"Love Jane Forever");
+
+	let mut buffer = *b"4C6F7665204A616E6520466F7265766572";
+	assert_eq!(dehexify_in_place(&mut buffer).unwrap(), b"Love Jane Forever");
+
+	assert_eq!(dehexify_in_place(&mut []), Ok([].as_mut_slice()));
+	assert_eq!(dehexify_in_place(&mut { *b"0x" }), Ok([].as_mut_slice()));
+	assert_eq!(
+		dehexify_in_place(&mut { *b"0x0" }),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
+	assert_eq!(
+		dehexify_in_place(&mut { *b"00zz" }),
+		Err(Error::InvalidCharacter { character: 'z', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		dehexify_in_place(&mut { *b"0x00zz" }),
+		Err(Error::InvalidCharacter { character: 'z', index: 4, char_index: 4 })
+	);
+}
+
+/// Dehexify the hex in the given vector in place.
+///
+/// The vector is truncated to the decoded bytes, its capacity is kept.
+///
+/// Note that the vector is left partially overwritten if an error is encountered.
+///
+/// # Examples
+/// ```
+/// let mut vec = b"0x4c6f7665204a616e6520466f7265766572".to_vec();
+///
+/// array_bytes::dehexify_vec_in_place(&mut vec).unwrap();
+///
+/// assert_eq!(vec, b"Love Jane Forever");
+/// ```
+pub fn dehexify_vec_in_place(vec: &mut Vec<u8>) -> Result<()> {
+	let len = dehexify_in_place_inner(vec)?;
+
+	vec.truncate(len);
+
+	Ok(())
+}
+#[test]
+fn dehexify_vec_in_place_should_work() {
+	let mut vec = b"0x4c6f7665204a616e6520466f7265766572".to_vec();
+	assert_eq!(dehexify_vec_in_place(&mut vec), Ok(()));
+	assert_eq!(vec, b"Love Jane Forever");
+
+	let mut vec = b"4c6f7665204a616e6520466f7265766572".to_vec();
+	assert_eq!(dehexify_vec_in_place(&mut vec), Ok(()));
+	assert_eq!(vec, b"Love Jane Forever");
+
+	let mut vec = b"0x0".to_vec();
+	assert_eq!(
+		dehexify_vec_in_place(&mut vec),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
+	assert_eq!(vec, b"0x0");
+}
+
 /// Dehexify hex to a fixed length bytes vector then convert it to `T` where `T: From<[u8; N]>`.
 ///
 /// # Examples
@@ -259,7 +646,7 @@ where
 	H: AsRef<[u8]>,
 	T: From<[u8; N]>,
 {
-	Ok(dehexify_array(hex)?.into())
+	Ok(dehexify_array(hex, &HEX2DIGIT)?.into())
 }
 #[test]
 fn dehexify_array_then_into_should_work() {
@@ -304,7 +691,7 @@ where
 	H: AsRef<[u8]>,
 	T: From<Vec<u8>>,
 {
-	Ok(dehexify_bytes(hex.as_ref())?.into_vec().into())
+	Ok(dehexify_vec(hex, &HEX2DIGIT)?.into())
 }
 #[test]
 fn dehexify_vec_then_into_should_work() {
@@ -328,50 +715,263 @@ fn dehexify_vec_then_into_should_work() {
 }
 
 #[inline(always)]
-fn dehexify_array<H, const N: usize>(hex: H) -> Result<[u8; N]>
+fn dehexify_unsigned<H, T>(hex: H, table: &[Option<u8>; 256]) -> Result<T>
+where
+	H: AsRef<[u8]>,
+	T: FromStrRadix,
+{
+	let hex = hex.as_ref();
+	let offset = hex.len() - strip_0x(hex).len();
+
+	// Only the case-sensitive variants check the digits, `dehexify` keeps accepting whatever
+	// `from_str_radix` does, such as a leading `+`.
+	if !ptr::eq(table, &HEX2DIGIT) {
+		for i in offset..hex.len() {
+			dehexify_digit(hex, i, table)?;
+		}
+	}
+
+	let hex = str::from_utf8(&hex[offset..]).map_err(Error::Utf8Error)?;
+
+	T::from_str_radix(hex, 16).map_err(Error::ParseIntError)
+}
+
+pub(crate) trait FromStrRadix
+where
+	Self: Sized,
+{
+	fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+macro_rules! impl_from_str_radix {
+	($($t:ty,)+) => {
+		$(impl FromStrRadix for $t {
+			fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+				<$t>::from_str_radix(src, radix)
+			}
+		})+
+	};
+}
+impl_from_str_radix! {
+	usize,
+	u8,
+	u16,
+	u32,
+	u64,
+	u128,
+}
+
+#[inline(always)]
+fn dehexify_slice_mut_with<'a, H>(
+	hex: H,
+	slice_src: &'a mut [u8],
+	table: &[Option<u8>; 256],
+) -> Result<&'a [u8]>
+where
+	H: AsRef<[u8]>,
+{
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+	let len = (hex.len() - offset) >> 1;
+
+	if len != slice_src.len() {
+		Err(Error::MismatchedLength {
+			expect: slice_src.len(),
+			actual: len,
+			unit: LengthUnit::Byte,
+		})?;
+	}
+
+	for (byte, i) in slice_src.iter_mut().zip((offset..hex.len()).step_by(2)) {
+		*byte = dehexify_ascii(hex, i, table)?;
+	}
+
+	Ok(slice_src)
+}
+
+#[inline(always)]
+fn dehexify_array<H, const N: usize>(hex: H, table: &[Option<u8>; 256]) -> Result<[u8; N]>
 where
 	H: AsRef<[u8]>,
 {
-	let bytes = dehexify_bytes(hex)?;
+	let mut array = MaybeUninit::<[u8; N]>::uninit();
+
+	dehexify_array_uninit_with(hex, &mut array, table)?;
+
+	// Fully initialized in previous step; qed.
+	Ok(unsafe { array.assume_init() })
+}
+
+#[inline(always)]
+fn dehexify_array_uninit_with<'a, H, const N: usize>(
+	hex: H,
+	array: &'a mut MaybeUninit<[u8; N]>,
+	table: &[Option<u8>; 256],
+) -> Result<&'a mut [u8; N]>
+where
+	H: AsRef<[u8]>,
+{
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+
+	let len = (hex.len() - offset) >> 1;
+
+	if len != N {
+		Err(Error::MismatchedLength { expect: N, actual: len, unit: LengthUnit::Byte })?;
+	}
 
-	op::slice2array(&bytes)
+	// `hex` has exactly `N * 2` digits; qed.
+	unsafe {
+		dehexify_ptr(hex, offset, array.as_mut_ptr().cast(), table)?;
+
+		Ok(array.assume_init_mut())
+	}
 }
 
 #[inline(always)]
-fn dehexify_bytes<H>(hex: H) -> Result<SmallVec<[u8; 64]>>
+fn dehexify_vec<H>(hex: H, table: &[Option<u8>; 256]) -> Result<Vec<u8>>
 where
 	H: AsRef<[u8]>,
 {
-	let hex = strip_0x(hex.as_ref());
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+	let cap = (hex.len() - offset) / 2;
+	let mut bytes = Vec::with_capacity(cap);
 
-	if hex.len() % 2 != 0 {
-		Err(Error::InvalidLength)?;
+	// The capacity is fixed, it's safe to set the length; qed.
+	unsafe {
+		dehexify_ptr(hex, offset, bytes.as_mut_ptr(), table)?;
+		bytes.set_len(cap);
 	}
 
-	let cap = hex.len() / 2;
-	let mut bytes = <SmallVec<[u8; 64]>>::with_capacity(cap);
+	Ok(bytes)
+}
+
+#[inline(always)]
+fn dehexify_bytes<H, const N: usize>(hex: H, table: &[Option<u8>; 256]) -> Result<SmallVec<[u8; N]>>
+where
+	H: AsRef<[u8]>,
+{
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+	let cap = (hex.len() - offset) / 2;
+	let mut bytes = <SmallVec<[u8; N]>>::with_capacity(cap);
 
 	// The capacity is fixed, it's safe to set the length; qed.
 	unsafe {
+		dehexify_ptr(hex, offset, bytes.as_mut_ptr(), table)?;
 		bytes.set_len(cap);
 	}
 
-	let bytes_ptr = bytes.as_mut_ptr();
+	Ok(bytes)
+}
 
-	for i in 0..cap {
-		let high = HEX2DIGIT[hex[i * 2] as usize]
-			.ok_or(Error::InvalidCharacter { character: hex[i * 2] as char, index: i * 2 })?;
-		let low = HEX2DIGIT[hex[i * 2 + 1] as usize].ok_or(Error::InvalidCharacter {
-			character: hex[i * 2 + 1] as char,
-			index: i * 2 + 1,
-		})?;
+#[cfg(feature = "arrayvec")]
+#[inline(always)]
+fn dehexify_array_vec<H, const N: usize>(
+	hex: H,
+	table: &[Option<u8>; 256],
+) -> Result<arrayvec::ArrayVec<u8, N>>
+where
+	H: AsRef<[u8]>,
+{
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+	let len = (hex.len() - offset) / 2;
+
+	if len > N {
+		Err(Error::CapacityOverflow { capacity: N, length: len })?;
+	}
+
+	let mut bytes = <arrayvec::ArrayVec<u8, N>>::new();
+
+	// The capacity is checked, it's safe to set the length; qed.
+	unsafe {
+		dehexify_ptr(hex, offset, bytes.as_mut_ptr(), table)?;
+		bytes.set_len(len);
+	}
+
+	Ok(bytes)
+}
+
+#[cfg(feature = "heapless")]
+#[inline(always)]
+fn dehexify_heapless_vec<H, const N: usize>(
+	hex: H,
+	table: &[Option<u8>; 256],
+) -> Result<heapless::Vec<u8, N>>
+where
+	H: AsRef<[u8]>,
+{
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+	let len = (hex.len() - offset) / 2;
+
+	if len > N {
+		Err(Error::CapacityOverflow { capacity: N, length: len })?;
+	}
+
+	let mut bytes = <heapless::Vec<u8, N>>::new();
+
+	// The capacity is checked, it's safe to set the length; qed.
+	unsafe {
+		dehexify_ptr(hex, offset, bytes.as_mut_ptr(), table)?;
+		bytes.set_len(len);
+	}
+
+	Ok(bytes)
+}
+
+#[inline(always)]
+fn dehexify_in_place_inner(buffer: &mut [u8]) -> Result<usize> {
+	let offset = offset_0x_even(buffer)?;
+	let len = (buffer.len() - offset) / 2;
+
+	// The `i`th byte is always written behind the digits which are going to be read; qed.
+	for i in 0..len {
+		buffer[i] = dehexify_ascii(buffer, offset + i * 2, &HEX2DIGIT)?;
+	}
+
+	Ok(len)
+}
+
+/// Dehexify the even length `hex[offset..]` into `bytes_ptr`.
+///
+/// # Safety
+/// `bytes_ptr` must be valid for `(hex.len() - offset) / 2` writes.
+#[inline(always)]
+unsafe fn dehexify_ptr(
+	hex: &[u8],
+	offset: usize,
+	bytes_ptr: *mut u8,
+	table: &[Option<u8>; 256],
+) -> Result<()> {
+	for i in 0..(hex.len() - offset) / 2 {
+		let byte = dehexify_ascii(hex, offset + i * 2, table)?;
 
 		unsafe {
-			*bytes_ptr.add(i) = (high << 4) | low;
+			*bytes_ptr.add(i) = byte;
 		}
 	}
 
-	Ok(bytes)
+	Ok(())
+}
+
+/// Return the offset of the digits, which is `2` if `hex` is `0x` prefixed, otherwise `0`.
+///
+/// The number of the digits must be even.
+#[inline(always)]
+pub(super) fn offset_0x_even(hex: &[u8]) -> Result<usize> {
+	let digits = strip_0x(hex);
+
+	if !digits.len().is_multiple_of(2) {
+		Err(Error::InvalidLength {
+			expect: digits.len() + 1,
+			actual: digits.len(),
+			unit: LengthUnit::Nibble,
+		})?;
+	}
+
+	Ok(hex.len() - digits.len())
 }
 
 #[inline(always)]
@@ -379,12 +979,14 @@ pub(super) fn strip_0x(hex: &[u8]) -> &[u8] {
 	if hex.len() >= 2 && hex[0] == b'0' && hex[1] == b'x' { &hex[2..] } else { hex }
 }
 
+/// Dehexify the two digits starting at `i`.
 #[inline(always)]
-fn dehexify_ascii((hex0, hex0_idx): (&u8, usize), (hex1, hex1_idx): (&u8, usize)) -> Result<u8> {
-	let ascii = HEX2DIGIT[*hex0 as usize]
-		.ok_or(Error::InvalidCharacter { character: *hex0 as _, index: hex0_idx })?
-		<< 4 | HEX2DIGIT[*hex1 as usize]
-		.ok_or(Error::InvalidCharacter { character: *hex1 as _, index: hex1_idx })?;
+pub(super) fn dehexify_ascii(hex: &[u8], i: usize, table: &[Option<u8>; 256]) -> Result<u8> {
+	Ok(dehexify_digit(hex, i, table)? << 4 | dehexify_digit(hex, i + 1, table)?)
+}
 
-	Ok(ascii)
+#[inline(always)]
+fn dehexify_digit(hex: &[u8], i: usize, table: &[Option<u8>; 256]) -> Result<u8> {
+	// The digits before `i` are all checked, they are ASCII; qed.
+	table[hex[i] as usize].ok_or_else(|| Error::invalid_character(&hex[i..], i, i))
 }
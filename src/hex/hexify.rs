@@ -3,8 +3,8 @@ use core::{mem, str};
 // self
 use crate::prelude::*;
 
-const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
-const HEX_CHARS_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+pub(super) const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+pub(super) const HEX_CHARS_UPPER: &[u8; 16] = b"0123456789ABCDEF";
 
 /// Hexify `Self`.
 ///
@@ -297,7 +297,8 @@ fn highest_set_bit_should_work() {
 pub fn hexify_hex_bytes(bytes: &[u8]) -> Result<&str> {
 	for (i, byte) in bytes.iter().enumerate().skip(if bytes.starts_with(b"0x") { 2 } else { 0 }) {
 		if !byte.is_ascii_hexdigit() {
-			Err(Error::InvalidCharacter { character: *byte as _, index: i })?;
+			// The bytes before `i` are all checked, they are ASCII; qed.
+			Err(Error::invalid_character(&bytes[i..], i, i))?;
 		}
 	}
 
@@ -324,4 +325,10 @@ fn hexify_hex_bytes_should_work() {
 		hexify_hex_bytes(b"0x4C6F7665204A616E6520466F7265766572"),
 		Ok("0x4C6F7665204A616E6520466F7265766572"),
 	);
+
+	assert_eq!(
+		hexify_hex_bytes("0x我爱你".as_bytes()),
+		Err(Error::InvalidCharacter { character: '我', index: 2, char_index: 2 })
+	);
+	assert_eq!(hexify_hex_bytes(b"0x\xff\xfe"), Err(Error::InvalidByte { byte: 0xff, index: 2 }));
 }
@@ -0,0 +1,260 @@
+// alloc
+use alloc::boxed::Box;
+// self
+use super::{dehexify::Dehexify, hexify::Hexify};
+use crate::prelude::*;
+
+/// Dehexify a list of hex separated by the delimiter.
+///
+/// The delimiter must be an ASCII character other than the hex digits, or it panics. Each element
+/// is trimmed of the ASCII whitespace around it, and empty elements are skipped, which makes it fit
+/// for both `0xaa,0xbb` and one hex per line.
+///
+/// If an element fails, the error is wrapped in [`Error::InvalidElement`] with the element's index.
+/// The inner error's offsets are against the whole input.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Error;
+///
+/// assert_eq!(
+/// 	array_bytes::dehexify_list::<[u8; 2], _>("0x4c6f, 0x7665", b','),
+/// 	Ok(vec![*b"Lo", *b"ve"])
+/// );
+/// assert_eq!(array_bytes::dehexify_list::<u32, _>("0x34\n0x208\n", b'\n'), Ok(vec![52, 520]));
+/// assert_eq!(
+/// 	array_bytes::dehexify_list::<Vec<u8>, _>("0x4c6f,0x76zz", b','),
+/// 	Err(Error::InvalidElement {
+/// 		element: 1,
+/// 		source: Box::new(Error::InvalidCharacter { character: 'z', index: 11, char_index: 11 }),
+/// 	})
+/// );
+/// ```
+pub fn dehexify_list<T, H>(hex: H, delimiter: u8) -> Result<Vec<T>>
+where
+	T: Dehexify,
+	H: AsRef<[u8]>,
+{
+	check_delimiter(delimiter);
+
+	let hex = hex.as_ref();
+	let mut start = 0;
+
+	hex.split(|&b| b == delimiter)
+		.enumerate()
+		.filter_map(|(element, piece)| {
+			let trimmed = piece.trim_ascii_start();
+			let offset = start + piece.len() - trimmed.len();
+			let trimmed = trimmed.trim_ascii_end();
+
+			start += piece.len() + 1;
+
+			if trimmed.is_empty() {
+				return None;
+			}
+
+			// The previous elements are all valid, so the bytes before this one are all ASCII.
+			Some(T::dehexify(trimmed).map_err(|e| Error::InvalidElement {
+				element,
+				source: Box::new(e.rebase(offset, offset)),
+			}))
+		})
+		.collect()
+}
+#[test]
+fn dehexify_list_should_work() {
+	assert_eq!(
+		dehexify_list::<[u8; 2], _>("0x4c6f,0x7665,4a61", b','),
+		Ok(alloc::vec![*b"Lo", *b"ve", *b"Ja"])
+	);
+	assert_eq!(
+		dehexify_list::<Vec<u8>, _>("\t0x4c6f \r\n\r\n 0x766572\r\n", b'\n'),
+		Ok(alloc::vec![b"Lo".to_vec(), b"ver".to_vec()])
+	);
+	assert_eq!(dehexify_list::<u16, _>("34 208 0x5", b' '), Ok(alloc::vec![52, 520, 5]));
+	assert_eq!(dehexify_list::<Vec<u8>, _>("", b','), Ok(Vec::new()));
+	assert_eq!(dehexify_list::<Vec<u8>, _>(" , ,", b','), Ok(Vec::new()));
+
+	assert_eq!(
+		dehexify_list::<[u8; 2], _>("0x4c6f,0x766", b','),
+		Err(Error::InvalidElement {
+			element: 1,
+			source: Box::new(Error::InvalidLength {
+				expect: 4,
+				actual: 3,
+				unit: LengthUnit::Nibble
+			})
+		})
+	);
+	assert_eq!(
+		dehexify_list::<[u8; 2], _>("0x4c6f,0x76", b','),
+		Err(Error::InvalidElement {
+			element: 1,
+			source: Box::new(Error::MismatchedLength {
+				expect: 2,
+				actual: 1,
+				unit: LengthUnit::Byte
+			})
+		})
+	);
+	assert_eq!(
+		dehexify_list::<Vec<u8>, _>("4c\n0x4c6f\n  0x76我5", b'\n'),
+		Err(Error::InvalidElement {
+			element: 2,
+			source: Box::new(Error::InvalidCharacter {
+				character: '我',
+				index: 16,
+				char_index: 16
+			})
+		})
+	);
+	assert_eq!(
+		dehexify_list::<Vec<u8>, _>(b"00,\xff0", b','),
+		Err(Error::InvalidElement {
+			element: 1,
+			source: Box::new(Error::InvalidByte { byte: 0xff, index: 3 })
+		})
+	);
+	assert!(matches!(
+		dehexify_list::<u8, _>("0x34,0x100", b','),
+		Err(Error::InvalidElement { element: 1, source }) if matches!(*source, Error::ParseIntError(_))
+	));
+}
+#[test]
+#[should_panic = "the delimiter(0xb7) must be an ASCII character other than the hex digits"]
+fn dehexify_list_with_non_ascii_delimiter_should_panic() {
+	let _ = dehexify_list::<Vec<u8>, _>("4c6f\u{b7}7665", 0xb7);
+}
+#[test]
+#[should_panic = "the delimiter(0x61) must be an ASCII character other than the hex digits"]
+fn dehexify_list_with_hex_digit_delimiter_should_panic() {
+	let _ = dehexify_list::<Vec<u8>, _>("4c6fa7665", b'a');
+}
+
+/// Hexify a list of items and join them with the delimiter.
+///
+/// The delimiter must be an ASCII character other than the hex digits, or it panics. This is the
+/// complement of [`dehexify_list`].
+///
+/// # Examples
+/// ```
+/// assert_eq!(array_bytes::hexify_list(&[*b"Lo", *b"ve"], b','), "4c6f,7665");
+/// assert_eq!(array_bytes::hexify_list_upper(&[b"Lo".to_vec()], b','), "4C6F");
+/// assert_eq!(array_bytes::hexify_list_prefixed(&[52_u32, 520], b'\n'), "0x34\n0x208");
+/// assert_eq!(array_bytes::hexify_list_prefixed_upper(&[*b"Lo", *b"ve"], b' '), "0x4C6F 0x7665");
+/// ```
+pub fn hexify_list<'a, I, T>(items: I, delimiter: u8) -> String
+where
+	I: IntoIterator<Item = &'a T>,
+	T: 'a + ?Sized + Hexify,
+{
+	hexify_list_with(items, delimiter, T::hexify)
+}
+#[test]
+fn hexify_list_should_work() {
+	assert_eq!(hexify_list(&[*b"Lo", *b"ve"], b','), "4c6f,7665");
+	assert_eq!(hexify_list(&[b"Lo".to_vec(), b"ver".to_vec()], b'\n'), "4c6f\n766572");
+	assert_eq!(hexify_list([b"Lo".as_slice()].into_iter(), b','), "4c6f");
+	assert_eq!(hexify_list(&[52_u8, 0], b' '), "34 0");
+	assert_eq!(hexify_list::<_, Vec<u8>>(&[], b','), "");
+
+	let list = [*b"Love", *b"Jane"];
+	assert_eq!(dehexify_list::<[u8; 4], _>(hexify_list(&list, b','), b','), Ok(list.to_vec()));
+}
+#[test]
+#[should_panic = "the delimiter(0xb7) must be an ASCII character other than the hex digits"]
+fn hexify_list_with_non_ascii_delimiter_should_panic() {
+	hexify_list(&[*b"Lo", *b"ve"], 0xb7);
+}
+#[test]
+#[should_panic = "the delimiter(0x61) must be an ASCII character other than the hex digits"]
+fn hexify_list_with_hex_digit_delimiter_should_panic() {
+	hexify_list(&[*b"Lo", *b"ve"], b'a');
+}
+
+/// Hexify a list of items with uppercase and join them with the delimiter.
+///
+/// # Examples
+/// ```
+/// assert_eq!(array_bytes::hexify_list_upper(&[*b"Lo", *b"ve"], b','), "4C6F,7665");
+/// ```
+pub fn hexify_list_upper<'a, I, T>(items: I, delimiter: u8) -> String
+where
+	I: IntoIterator<Item = &'a T>,
+	T: 'a + ?Sized + Hexify,
+{
+	hexify_list_with(items, delimiter, T::hexify_upper)
+}
+#[test]
+fn hexify_list_upper_should_work() {
+	assert_eq!(hexify_list_upper(&[*b"Lo", *b"ve"], b','), "4C6F,7665");
+	assert_eq!(hexify_list_upper(&[520_u16], b','), "208");
+}
+
+/// Hexify a list of items with `0x` prefix and join them with the delimiter.
+///
+/// # Examples
+/// ```
+/// assert_eq!(array_bytes::hexify_list_prefixed(&[*b"Lo", *b"ve"], b','), "0x4c6f,0x7665");
+/// ```
+pub fn hexify_list_prefixed<'a, I, T>(items: I, delimiter: u8) -> String
+where
+	I: IntoIterator<Item = &'a T>,
+	T: 'a + ?Sized + Hexify,
+{
+	hexify_list_with(items, delimiter, T::hexify_prefixed)
+}
+#[test]
+fn hexify_list_prefixed_should_work() {
+	assert_eq!(hexify_list_prefixed(&[*b"Lo", *b"ve"], b','), "0x4c6f,0x7665");
+	assert_eq!(hexify_list_prefixed(&[0_u32, 520], b'\n'), "0x0\n0x208");
+}
+
+/// Hexify a list of items with `0x` prefix and uppercase and join them with the delimiter.
+///
+/// # Examples
+/// ```
+/// assert_eq!(array_bytes::hexify_list_prefixed_upper(&[*b"Lo", *b"ve"], b','), "0x4C6F,0x7665");
+/// ```
+pub fn hexify_list_prefixed_upper<'a, I, T>(items: I, delimiter: u8) -> String
+where
+	I: IntoIterator<Item = &'a T>,
+	T: 'a + ?Sized + Hexify,
+{
+	hexify_list_with(items, delimiter, T::hexify_prefixed_upper)
+}
+#[test]
+fn hexify_list_prefixed_upper_should_work() {
+	assert_eq!(hexify_list_prefixed_upper(&[*b"Lo", *b"ve"], b','), "0x4C6F,0x7665");
+	assert_eq!(hexify_list_prefixed_upper(&[b"ver".to_vec()], b','), "0x766572");
+}
+
+fn hexify_list_with<'a, I, T, F>(items: I, delimiter: u8, f: F) -> String
+where
+	I: IntoIterator<Item = &'a T>,
+	T: 'a + ?Sized,
+	F: Fn(&T) -> String,
+{
+	check_delimiter(delimiter);
+
+	let mut hex = String::new();
+
+	for (i, item) in items.into_iter().enumerate() {
+		if i != 0 {
+			hex.push(delimiter as _);
+		}
+
+		hex.push_str(&f(item));
+	}
+
+	hex
+}
+
+// A non-ASCII delimiter is written as a multi-byte character and a hex digit one can't be told
+// apart from the hex, either would break the round trip.
+fn check_delimiter(delimiter: u8) {
+	assert!(
+		delimiter.is_ascii() && !delimiter.is_ascii_hexdigit(),
+		"the delimiter({delimiter:#04x}) must be an ASCII character other than the hex digits"
+	);
+}
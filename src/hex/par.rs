@@ -0,0 +1,230 @@
+// crates.io
+use rayon::prelude::*;
+// self
+use super::{
+	dehexify::{HEX2DIGIT, dehexify_ascii, offset_0x_even},
+	hexify::{HEX_CHARS, HEX_CHARS_UPPER},
+};
+use crate::prelude::*;
+
+// The number of bytes processed by each task.
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// Hexify `Self` in parallel.
+///
+/// The input is split at byte boundaries and each piece is hexified into its own part of the
+/// output. The output is identical to [`Hexify`](crate::Hexify)'s.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Hexify, ParHexify};
+///
+/// let data = vec![52; 1024 * 1024];
+///
+/// assert_eq!(data.par_hexify(), data.hexify());
+/// assert_eq!(data.par_hexify_upper(), data.hexify_upper());
+/// assert_eq!(data.par_hexify_prefixed(), data.hexify_prefixed());
+/// assert_eq!(data.par_hexify_prefixed_upper(), data.hexify_prefixed_upper());
+/// ```
+pub trait ParHexify {
+	/// Hexify `Self` in parallel.
+	fn par_hexify(&self) -> String;
+
+	/// Hexify `Self` in parallel with uppercase.
+	fn par_hexify_upper(&self) -> String;
+
+	/// Hexify `Self` in parallel with `0x` prefix.
+	fn par_hexify_prefixed(&self) -> String;
+
+	/// Hexify `Self` in parallel with `0x` prefix and uppercase.
+	fn par_hexify_prefixed_upper(&self) -> String;
+}
+macro_rules! par_hexify_bytes_fns {
+	() => {
+		fn par_hexify(&self) -> String {
+			par_hexify(self, "", HEX_CHARS)
+		}
+
+		fn par_hexify_upper(&self) -> String {
+			par_hexify(self, "", HEX_CHARS_UPPER)
+		}
+
+		fn par_hexify_prefixed(&self) -> String {
+			par_hexify(self, "0x", HEX_CHARS)
+		}
+
+		fn par_hexify_prefixed_upper(&self) -> String {
+			par_hexify(self, "0x", HEX_CHARS_UPPER)
+		}
+	};
+}
+impl<const N: usize> ParHexify for [u8; N] {
+	par_hexify_bytes_fns! {}
+}
+impl ParHexify for [u8] {
+	par_hexify_bytes_fns! {}
+}
+impl ParHexify for Vec<u8> {
+	par_hexify_bytes_fns! {}
+}
+#[test]
+fn par_hexify_should_work() {
+	use crate::Hexify;
+
+	let data = test_data();
+
+	assert_eq!(data.par_hexify(), data.hexify());
+	assert_eq!(data.par_hexify_upper(), data.hexify_upper());
+	assert_eq!(data.par_hexify_prefixed(), data.hexify_prefixed());
+	assert_eq!(data.par_hexify_prefixed_upper(), data.hexify_prefixed_upper());
+	assert_eq!([].par_hexify_prefixed(), "0x");
+}
+
+/// Dehexify hex to bytes in parallel.
+///
+/// The digits are split at byte boundaries and each piece is dehexified into its own part of the
+/// output. The output and the errors are identical to [`Dehexify`](crate::Dehexify)'s.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Error, Hexify};
+///
+/// let data = vec![52; 1024 * 1024];
+///
+/// assert_eq!(array_bytes::par_dehexify(data.hexify_prefixed()), Ok(data));
+/// assert_eq!(
+/// 	array_bytes::par_dehexify("0x00zz"),
+/// 	Err(Error::InvalidCharacter { character: 'z', index: 4, char_index: 4 })
+/// );
+/// ```
+pub fn par_dehexify<H>(hex: H) -> Result<Vec<u8>>
+where
+	H: AsRef<[u8]>,
+{
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+	let mut bytes = alloc::vec![0; (hex.len() - offset) / 2];
+
+	par_dehexify_inner(hex, offset, &mut bytes)?;
+
+	Ok(bytes)
+}
+#[test]
+fn par_dehexify_should_work() {
+	use crate::{Dehexify, Hexify};
+
+	let data = test_data();
+
+	assert_eq!(par_dehexify(data.hexify()), Ok(data.clone()));
+	assert_eq!(par_dehexify(data.hexify_prefixed_upper()), Ok(data.clone()));
+	assert_eq!(par_dehexify("0x"), Ok(Vec::new()));
+	assert_eq!(
+		par_dehexify("0x0"),
+		Err(Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble })
+	);
+
+	let mut hex = data.hexify_prefixed().into_bytes();
+	let (first, second) = (2 + CHUNK_SIZE * 2 + 7, 2 + CHUNK_SIZE * 4 + 1);
+
+	hex[second] = b'z';
+	assert_eq!(par_dehexify(&hex), <Vec<u8>>::dehexify(&hex));
+	assert_eq!(
+		par_dehexify(&hex),
+		Err(Error::InvalidCharacter { character: 'z', index: second, char_index: second })
+	);
+
+	hex[first] = b'g';
+	assert_eq!(par_dehexify(&hex), <Vec<u8>>::dehexify(&hex));
+	assert_eq!(
+		par_dehexify(&hex),
+		Err(Error::InvalidCharacter { character: 'g', index: first, char_index: first })
+	);
+}
+
+/// Dehexify hex into a mutable slice source in parallel.
+///
+/// The output and the errors are identical to [`dehexify_slice_mut`](crate::dehexify_slice_mut)'s.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Hexify;
+///
+/// let data = vec![52; 1024 * 1024];
+/// let mut bytes = vec![0; 1024 * 1024];
+///
+/// assert_eq!(array_bytes::par_dehexify_slice_mut(data.hexify(), &mut bytes), Ok(data.as_slice()));
+/// ```
+pub fn par_dehexify_slice_mut<H>(hex: H, slice_src: &mut [u8]) -> Result<&[u8]>
+where
+	H: AsRef<[u8]>,
+{
+	let hex = hex.as_ref();
+	let offset = offset_0x_even(hex)?;
+	let len = (hex.len() - offset) / 2;
+
+	if len != slice_src.len() {
+		Err(Error::MismatchedLength {
+			expect: slice_src.len(),
+			actual: len,
+			unit: LengthUnit::Byte,
+		})?;
+	}
+
+	par_dehexify_inner(hex, offset, slice_src)?;
+
+	Ok(slice_src)
+}
+#[test]
+fn par_dehexify_slice_mut_should_work() {
+	use crate::Hexify;
+
+	let data = test_data();
+	let mut bytes = alloc::vec![0; data.len()];
+
+	assert_eq!(par_dehexify_slice_mut(data.hexify(), &mut bytes), Ok(data.as_slice()));
+	assert_eq!(
+		par_dehexify_slice_mut("0x0001", &mut [0]),
+		Err(Error::MismatchedLength { expect: 1, actual: 2, unit: LengthUnit::Byte })
+	);
+}
+
+fn par_hexify(bytes: &[u8], prefix: &str, map: &[u8; 16]) -> String {
+	let mut hex = alloc::vec![0; prefix.len() + bytes.len() * 2];
+
+	hex[..prefix.len()].copy_from_slice(prefix.as_bytes());
+	hex[prefix.len()..].par_chunks_mut(CHUNK_SIZE * 2).zip(bytes.par_chunks(CHUNK_SIZE)).for_each(
+		|(hex, bytes)| {
+			for (hex, &byte) in hex.as_chunks_mut::<2>().0.iter_mut().zip(bytes) {
+				hex[0] = map[(byte >> 4) as usize];
+				hex[1] = map[(byte & 0x0f) as usize];
+			}
+		},
+	);
+
+	// All the bytes are looked up in the map, it's safe to convert to string; qed.
+	unsafe { String::from_utf8_unchecked(hex) }
+}
+
+fn par_dehexify_inner(hex: &[u8], offset: usize, bytes: &mut [u8]) -> Result<()> {
+	// Find the first failed chunk, to report the first invalid character of the whole input.
+	bytes
+		.par_chunks_mut(CHUNK_SIZE)
+		.enumerate()
+		.map(|(i, bytes)| {
+			let start = offset + i * CHUNK_SIZE * 2;
+
+			for (j, byte) in bytes.iter_mut().enumerate() {
+				*byte = dehexify_ascii(hex, start + j * 2, &HEX2DIGIT)?;
+			}
+
+			Ok(())
+		})
+		.find_first(Result::is_err)
+		.unwrap_or(Ok(()))
+}
+
+#[cfg(test)]
+fn test_data() -> Vec<u8> {
+	[include_bytes!("../../LICENSE-APACHE2").as_slice(), include_bytes!("../../LICENSE-GPL3")]
+		.concat()
+}
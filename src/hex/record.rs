@@ -0,0 +1,655 @@
+// alloc
+use alloc::collections::BTreeMap;
+// self
+use super::{
+	dehexify::{HEX2DIGIT, dehexify_ascii},
+	hexify::HEX_CHARS_UPPER,
+};
+use crate::prelude::*;
+
+/// A sparse memory image, read from or written to the record formats.
+///
+/// The written bytes are kept as contiguous segments, adjacent writes are merged.
+///
+/// # Examples
+/// ```
+/// let mut image = array_bytes::MemoryImage::new();
+///
+/// assert!(image.insert(0x0100, b"Love"));
+/// assert!(image.insert(0x0104, b"Jane"));
+/// assert!(!image.insert(0x0102, b"ve"));
+/// assert_eq!(image.segments().collect::<Vec<_>>(), [(0x0100, b"LoveJane".as_slice())]);
+/// assert_eq!(image.get(0x0104), Some(b'J'));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryImage {
+	segments: BTreeMap<u32, Vec<u8>>,
+	/// The start address, from the start address records.
+	pub start_address: Option<u32>,
+}
+impl MemoryImage {
+	/// Create an empty image.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Whether nothing is written.
+	pub fn is_empty(&self) -> bool {
+		self.segments.is_empty()
+	}
+
+	/// Write the data at the address.
+	///
+	/// Return `false` and leave the image untouched if any of the bytes is already written or the
+	/// data goes beyond the 32-bit address space.
+	pub fn insert(&mut self, address: u32, data: &[u8]) -> bool {
+		if data.is_empty() {
+			return true;
+		}
+
+		let end = address as u64 + data.len() as u64;
+
+		if end > 1 << 32 {
+			return false;
+		}
+		if self
+			.segments
+			.range(..=address)
+			.next_back()
+			.is_some_and(|(&a, d)| a as u64 + d.len() as u64 > address as u64)
+		{
+			return false;
+		}
+		if self.segments.range(address..).next().is_some_and(|(&a, _)| (a as u64) < end) {
+			return false;
+		}
+
+		let start = match self.segments.range_mut(..address).next_back() {
+			Some((&a, d)) if a as u64 + d.len() as u64 == address as u64 => {
+				d.extend_from_slice(data);
+
+				a
+			},
+			_ => {
+				self.segments.insert(address, data.to_vec());
+
+				address
+			},
+		};
+
+		if end < 1 << 32
+			&& let Some(next) = self.segments.remove(&(end as u32))
+		{
+			// The segment is just inserted or extended; qed.
+			self.segments.get_mut(&start).unwrap().extend(next);
+		}
+
+		true
+	}
+
+	/// Read the byte at the address.
+	pub fn get(&self, address: u32) -> Option<u8> {
+		let (a, d) = self.segments.range(..=address).next_back()?;
+
+		d.get((address - a) as usize).copied()
+	}
+
+	/// Iterate over the contiguous segments in address order.
+	pub fn segments(&self) -> impl Iterator<Item = (u32, &[u8])> {
+		self.segments.iter().map(|(&a, d)| (a, d.as_slice()))
+	}
+}
+#[test]
+fn memory_image_should_work() {
+	let mut image = MemoryImage::new();
+
+	assert!(image.is_empty());
+	assert!(image.insert(0x10, b"Jane"));
+	assert!(image.insert(0x08, b"Love"));
+	assert!(image.insert(0x0c, b" "));
+	assert!(image.insert(0x0d, b"..."));
+	assert!(image.insert(0x14, &[]));
+	assert_eq!(image.segments().collect::<Vec<_>>(), [(0x08, b"Love ...Jane".as_slice())]);
+
+	assert!(!image.insert(0x07, b"..."));
+	assert!(!image.insert(0x13, b"..."));
+	assert!(!image.insert(0x0a, b"."));
+	assert!(!image.insert(u32::MAX, b".."));
+	assert!(image.insert(u32::MAX, b"."));
+	assert_eq!(
+		image.segments().collect::<Vec<_>>(),
+		[(0x08, b"Love ...Jane".as_slice()), (u32::MAX, b".")]
+	);
+
+	assert_eq!(image.get(0x07), None);
+	assert_eq!(image.get(0x08), Some(b'L'));
+	assert_eq!(image.get(0x13), Some(b'e'));
+	assert_eq!(image.get(0x14), None);
+	assert_eq!(image.get(u32::MAX), Some(b'.'));
+}
+
+/// Read an Intel HEX file into a [`MemoryImage`].
+///
+/// Every record's checksum is verified. The extended segment and extended linear address records
+/// are applied to the following data records, and the end of file record is required.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Error;
+///
+/// let image = array_bytes::read_ihex(
+/// 	":020000040001F9\n:044000004C6F766526\n:0400000500014000B6\n:00000001FF\n",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(image.segments().collect::<Vec<_>>(), [(0x0001_4000, b"Love".as_slice())]);
+/// assert_eq!(image.start_address, Some(0x0001_4000));
+/// assert_eq!(
+/// 	array_bytes::read_ihex(":044000004C6F766527\n:00000001FF"),
+/// 	Err(Error::InvalidChecksum { expect: 0x26, actual: 0x27, index: 17 })
+/// );
+/// ```
+pub fn read_ihex<H>(input: H) -> Result<MemoryImage>
+where
+	H: AsRef<[u8]>,
+{
+	let input = input.as_ref();
+	let mut image = MemoryImage::new();
+	let mut base = 0;
+	let mut bytes = Vec::new();
+
+	for (index, record) in records(input) {
+		if record[0] != b':' {
+			Err(Error::InvalidRecord { index })?;
+		}
+
+		let end = index + record.len();
+
+		dehexify_record(input, index, index + 1, end, &mut bytes)?;
+
+		// The byte count, the address, the record type and the checksum.
+		if bytes.len() < 5 || bytes[0] as usize != bytes.len() - 5 {
+			Err(Error::InvalidRecord { index })?;
+		}
+
+		let (checksum, bytes) = bytes.split_last().expect("checked above; qed");
+
+		verify_checksum(
+			bytes.iter().fold(0_u8, |sum, b| sum.wrapping_add(*b)).wrapping_neg(),
+			*checksum,
+			end - 2,
+		)?;
+
+		let offset = u16::from_be_bytes([bytes[1], bytes[2]]) as u32;
+		let data = &bytes[4..];
+
+		match (bytes[3], data.len()) {
+			(0x00, _) => insert(&mut image, base as u64 + offset as u64, data, index)?,
+			(0x01, 0) => return Ok(image),
+			(0x02, 2) => base = (u16::from_be_bytes([data[0], data[1]]) as u32) << 4,
+			(0x03, 4) =>
+				image.start_address = Some(
+					((u16::from_be_bytes([data[0], data[1]]) as u32) << 4)
+						+ u16::from_be_bytes([data[2], data[3]]) as u32,
+				),
+			(0x04, 2) => base = (u16::from_be_bytes([data[0], data[1]]) as u32) << 16,
+			(0x05, 4) =>
+				image.start_address = Some(u32::from_be_bytes([data[0], data[1], data[2], data[3]])),
+			_ => Err(Error::InvalidRecord { index })?,
+		}
+	}
+
+	// The end of file record is missing.
+	Err(Error::InvalidRecord { index: input.len() })
+}
+#[test]
+fn read_ihex_should_work() {
+	let image = read_ihex(
+		"\
+:0400000048656C6C77\r
+:02000002100CE0\r
+:03000000576F72C5\r
+:0400000300010002F6\r
+:00000001FF\r
+trailing data is ignored",
+	)
+	.unwrap();
+	assert_eq!(
+		image.segments().collect::<Vec<_>>(),
+		[(0x0000, b"Hell".as_slice()), (0x100c0, b"Wor")]
+	);
+	assert_eq!(image.start_address, Some(0x12));
+	assert_eq!(read_ihex("\n\n:00000001FF\n"), Ok(MemoryImage::new()));
+
+	assert_eq!(read_ihex(""), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_ihex(":00000000\n"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_ihex(":00000001FF0"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_ihex("\n;00000001FF"), Err(Error::InvalidRecord { index: 1 }));
+	assert_eq!(read_ihex(":0100000101FD\n:00000001FF"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_ihex(":02000000FF\n:00000001FF"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_ihex(":00000006FA\n"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(
+		read_ihex(":00000001FE"),
+		Err(Error::InvalidChecksum { expect: 0xff, actual: 0xfe, index: 9 })
+	);
+	assert_eq!(
+		read_ihex(":0000zz01FF"),
+		Err(Error::InvalidCharacter { character: 'z', index: 5, char_index: 5 })
+	);
+	assert_eq!(
+		read_ihex(":0100000000FF\n:0100000000FF\n:00000001FF"),
+		Err(Error::OverlappingRecord { address: 0, index: 14 })
+	);
+	assert_eq!(
+		read_ihex(":02000004FFFFFC\n:02FFFF00000000\n:00000001FF"),
+		Err(Error::InvalidRecord { index: 16 })
+	);
+}
+
+/// Write a [`MemoryImage`] as an Intel HEX file.
+///
+/// Each data record holds at most `record_size` bytes, which is clamped into `1..=255`. The
+/// extended linear address records are written for the addresses beyond 64 KiB.
+///
+/// # Examples
+/// ```
+/// let mut image = array_bytes::MemoryImage::new();
+///
+/// image.insert(0x0001_4000, b"Love");
+///
+/// assert_eq!(
+/// 	array_bytes::write_ihex(&image, 2),
+/// 	":020000040001F9\n:024000004C6F03\n:024002007665E1\n:00000001FF\n"
+/// );
+/// ```
+pub fn write_ihex(image: &MemoryImage, record_size: usize) -> String {
+	let record_size = record_size.clamp(1, 255);
+	let mut hex = String::new();
+	let mut upper = 0;
+
+	for (mut address, mut data) in image.segments() {
+		while !data.is_empty() {
+			// A data record must not cross the 64 KiB boundary.
+			let len = record_size.min(data.len()).min(0x10000 - (address & 0xffff) as usize);
+
+			if address >> 16 != upper {
+				upper = address >> 16;
+
+				write_ihex_record(&mut hex, 0x04, 0, &(upper as u16).to_be_bytes());
+			}
+
+			write_ihex_record(&mut hex, 0x00, address as u16, &data[..len]);
+
+			address = address.wrapping_add(len as u32);
+			data = &data[len..];
+		}
+	}
+
+	if let Some(start_address) = image.start_address {
+		write_ihex_record(&mut hex, 0x05, 0, &start_address.to_be_bytes());
+	}
+
+	write_ihex_record(&mut hex, 0x01, 0, &[]);
+
+	hex
+}
+#[test]
+fn write_ihex_should_work() {
+	let mut image = MemoryImage::new();
+
+	assert_eq!(write_ihex(&image, 16), ":00000001FF\n");
+
+	image.insert(0xfffe, b"Love");
+	image.insert(0x2_0000, b"Jane");
+	image.start_address = Some(0x0100);
+	assert_eq!(
+		write_ihex(&image, 0),
+		"\
+:01FFFE004CB6
+:01FFFF006F92
+:020000040001F9
+:010000007689
+:010001006599
+:020000040002F8
+:010000004AB5
+:01000100619D
+:010002006E8F
+:010003006597
+:0400000500000100F6
+:00000001FF
+"
+	);
+
+	let mut image = MemoryImage::new();
+
+	image.insert(0x1234, &(0..=255).collect::<Vec<_>>());
+	image.insert(0xffff_fff0, &[0; 16]);
+	image.start_address = Some(0xffff_fff0);
+
+	for record_size in [1, 16, 32, 255, 256] {
+		assert_eq!(read_ihex(write_ihex(&image, record_size)), Ok(image.clone()));
+	}
+}
+
+/// Read a Motorola S-record file into a [`MemoryImage`].
+///
+/// Every record's checksum is verified. The header records are skipped, the count records are
+/// checked against the number of data records, and the termination record is required.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Error;
+///
+/// let image =
+/// 	array_bytes::read_srec("S00600004844521B\nS10701004C6F766561\nS9030100FB\n").unwrap();
+///
+/// assert_eq!(image.segments().collect::<Vec<_>>(), [(0x0100, b"Love".as_slice())]);
+/// assert_eq!(image.start_address, Some(0x0100));
+/// assert_eq!(
+/// 	array_bytes::read_srec("S10701004C6F766562\nS9030000FC"),
+/// 	Err(Error::InvalidChecksum { expect: 0x61, actual: 0x62, index: 16 })
+/// );
+/// ```
+pub fn read_srec<H>(input: H) -> Result<MemoryImage>
+where
+	H: AsRef<[u8]>,
+{
+	let input = input.as_ref();
+	let mut image = MemoryImage::new();
+	let mut count = 0_u32;
+	let mut bytes = Vec::new();
+
+	for (index, record) in records(input) {
+		if record.len() < 2 || record[0] != b'S' {
+			Err(Error::InvalidRecord { index })?;
+		}
+
+		let end = index + record.len();
+
+		dehexify_record(input, index, index + 2, end, &mut bytes)?;
+
+		// The byte count covers the address, the data and the checksum.
+		if bytes.len() < 2 || bytes[0] as usize != bytes.len() - 1 {
+			Err(Error::InvalidRecord { index })?;
+		}
+
+		let (checksum, bytes) = bytes.split_last().expect("checked above; qed");
+
+		verify_checksum(
+			!bytes.iter().fold(0_u8, |sum, b| sum.wrapping_add(*b)),
+			*checksum,
+			end - 2,
+		)?;
+
+		let kind = record[1];
+		let address_len = match kind {
+			b'0' | b'1' | b'5' | b'9' => 2,
+			b'2' | b'6' | b'8' => 3,
+			b'3' | b'7' => 4,
+			_ => Err(Error::InvalidRecord { index })?,
+		};
+
+		if bytes.len() < 1 + address_len {
+			Err(Error::InvalidRecord { index })?;
+		}
+
+		let (address, data) = bytes[1..].split_at(address_len);
+		let address = address.iter().fold(0, |address, b| address << 8 | *b as u32);
+
+		match kind {
+			b'0' => (),
+			b'1' | b'2' | b'3' => {
+				insert(&mut image, address as u64, data, index)?;
+
+				count += 1;
+			},
+			b'5' | b'6' if data.is_empty() && address == count => (),
+			b'7' | b'8' | b'9' if data.is_empty() => {
+				image.start_address = Some(address);
+
+				return Ok(image);
+			},
+			_ => Err(Error::InvalidRecord { index })?,
+		}
+	}
+
+	// The termination record is missing.
+	Err(Error::InvalidRecord { index: input.len() })
+}
+#[test]
+fn read_srec_should_work() {
+	let image = read_srec(
+		"\
+S00F000068656C6C6F202020202000003C\r
+S11F00007C0802A6900100049421FFF07C6C1B787C8C23783C6000003863000026\r
+S11F001C4BFFFFE5398000007D83637880010014382100107C0803A64E800020E9\r
+S111003848656C6C6F20776F726C642E0A0042\r
+S5030003F9\r
+S9030000FC\r
+",
+	)
+	.unwrap();
+	assert_eq!(image.segments().count(), 1);
+	assert_eq!(image.get(0x38), Some(b'H'));
+	assert_eq!(image.get(0x45), Some(0x00));
+	assert_eq!(image.start_address, Some(0));
+
+	let image = read_srec("S2080100004C6F766560\nS308010000004A616EDD\nS70500000000FA").unwrap();
+	assert_eq!(
+		image.segments().collect::<Vec<_>>(),
+		[(0x01_0000, b"Love".as_slice()), (0x0100_0000, b"Jan")]
+	);
+	assert_eq!(
+		read_srec("S2080100004C6F766561\nS70500000000FA"),
+		Err(Error::InvalidChecksum { expect: 0x60, actual: 0x61, index: 18 })
+	);
+
+	assert_eq!(read_srec(""), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_srec("S"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_srec("S9030000FC0"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_srec("S9040000FB\n"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_srec("S4030000FC\n"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_srec("S3030000FC\n"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_srec("S5030001FB\nS9030000FC"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(read_srec("S904000000FB"), Err(Error::InvalidRecord { index: 0 }));
+	assert_eq!(
+		read_srec("S9030000FB"),
+		Err(Error::InvalidChecksum { expect: 0xfc, actual: 0xfb, index: 8 })
+	);
+	assert_eq!(
+		read_srec("S1040000zzFB\nS9030000FC"),
+		Err(Error::InvalidCharacter { character: 'z', index: 8, char_index: 8 })
+	);
+	assert_eq!(
+		read_srec("S104000000FB\nS104000000FB\nS9030000FC"),
+		Err(Error::OverlappingRecord { address: 0, index: 13 })
+	);
+}
+
+/// Write a [`MemoryImage`] as a Motorola S-record file.
+///
+/// Each data record holds at most `record_size` bytes, which is clamped to fit the byte count. The
+/// narrowest of the 16, 24 and 32-bit address records that covers the image is used.
+///
+/// # Examples
+/// ```
+/// let mut image = array_bytes::MemoryImage::new();
+///
+/// image.insert(0x0100, b"Love");
+///
+/// assert_eq!(
+/// 	array_bytes::write_srec(&image, 2),
+/// 	"S0030000FC\nS10501004C6F3E\nS105010276651C\nS5030002FA\nS9030000FC\n"
+/// );
+/// ```
+pub fn write_srec(image: &MemoryImage, record_size: usize) -> String {
+	let end = image
+		.segments()
+		.last()
+		.map_or(0, |(address, data)| address as u64 + data.len() as u64)
+		.max(image.start_address.map_or(0, |address| address as u64 + 1));
+	let (address_len, data_kind, termination_kind) = if end <= 1 << 16 {
+		(2, b'1', b'9')
+	} else if end <= 1 << 24 {
+		(3, b'2', b'8')
+	} else {
+		(4, b'3', b'7')
+	};
+	// The byte count also covers the address and the checksum.
+	let record_size = record_size.clamp(1, 255 - address_len - 1);
+	let mut hex = String::new();
+	let mut count = 0_u32;
+
+	write_srec_record(&mut hex, b'0', 0, 2, &[]);
+
+	for (mut address, data) in image.segments() {
+		for data in data.chunks(record_size) {
+			write_srec_record(&mut hex, data_kind, address, address_len, data);
+
+			address = address.wrapping_add(data.len() as u32);
+			count += 1;
+		}
+	}
+
+	if count <= 0xffff {
+		write_srec_record(&mut hex, b'5', count, 2, &[]);
+	} else if count <= 0xff_ffff {
+		write_srec_record(&mut hex, b'6', count, 3, &[]);
+	}
+
+	write_srec_record(
+		&mut hex,
+		termination_kind,
+		image.start_address.unwrap_or_default(),
+		address_len,
+		&[],
+	);
+
+	hex
+}
+#[test]
+fn write_srec_should_work() {
+	let mut image = MemoryImage::new();
+
+	assert_eq!(write_srec(&image, 16), "S0030000FC\nS5030000FC\nS9030000FC\n");
+
+	image.start_address = Some(0x01_0000);
+	assert_eq!(write_srec(&image, 16), "S0030000FC\nS5030000FC\nS804010000FA\n");
+
+	image.insert(0xff_fffe, b"Lo");
+	assert_eq!(
+		write_srec(&image, 1),
+		"S0030000FC\nS205FFFFFE4CB2\nS205FFFFFF6F8E\nS5030002FA\nS804010000FA\n"
+	);
+
+	image.insert(0x0100_0000, &(0..=255).collect::<Vec<_>>());
+	image.start_address = Some(0x0100_0000);
+	assert!(write_srec(&image, 16).contains("\nS70501000000F9\n"));
+
+	for record_size in [1, 16, 32, 255, 256] {
+		assert_eq!(read_srec(write_srec(&image, record_size)), Ok(image.clone()));
+	}
+}
+
+#[inline(always)]
+fn records(input: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+	let mut start = 0;
+
+	input.split(|b| *b == b'\n').filter_map(move |line| {
+		let index = start;
+		let record = line.trim_ascii();
+
+		start += line.len() + 1;
+
+		if record.is_empty() {
+			None
+		} else {
+			Some((index + line.len() - line.trim_ascii_start().len(), record))
+		}
+	})
+}
+
+#[inline(always)]
+fn dehexify_record(
+	input: &[u8],
+	index: usize,
+	start: usize,
+	end: usize,
+	bytes: &mut Vec<u8>,
+) -> Result<()> {
+	if !(end - start).is_multiple_of(2) {
+		Err(Error::InvalidRecord { index })?;
+	}
+
+	bytes.clear();
+
+	for i in (start..end).step_by(2) {
+		bytes.push(dehexify_ascii(input, i, &HEX2DIGIT)?);
+	}
+
+	Ok(())
+}
+
+#[inline(always)]
+fn verify_checksum(expect: u8, actual: u8, index: usize) -> Result<()> {
+	if expect != actual {
+		Err(Error::InvalidChecksum { expect, actual, index })?;
+	}
+
+	Ok(())
+}
+
+#[inline(always)]
+fn insert(image: &mut MemoryImage, address: u64, data: &[u8], index: usize) -> Result<()> {
+	if address + data.len() as u64 > 1 << 32 {
+		Err(Error::InvalidRecord { index })?;
+	}
+	if !image.insert(address as u32, data) {
+		Err(Error::OverlappingRecord { address: address as u32, index })?;
+	}
+
+	Ok(())
+}
+
+fn write_ihex_record(hex: &mut String, kind: u8, address: u16, data: &[u8]) {
+	let [high, low] = address.to_be_bytes();
+
+	hex.push(':');
+	write_record_bytes(hex, [data.len() as u8, high, low, kind].iter().chain(data), |sum| {
+		sum.wrapping_neg()
+	});
+}
+
+fn write_srec_record(hex: &mut String, kind: u8, address: u32, address_len: usize, data: &[u8]) {
+	hex.push('S');
+	hex.push(kind as _);
+	write_record_bytes(
+		hex,
+		[(address_len + data.len() + 1) as u8]
+			.iter()
+			.chain(&address.to_be_bytes()[4 - address_len..])
+			.chain(data),
+		|sum| !sum,
+	);
+}
+
+fn write_record_bytes<'a, I, F>(hex: &mut String, bytes: I, checksum: F)
+where
+	I: Iterator<Item = &'a u8>,
+	F: FnOnce(u8) -> u8,
+{
+	let mut push = |byte: u8| {
+		hex.push(HEX_CHARS_UPPER[(byte >> 4) as usize] as _);
+		hex.push(HEX_CHARS_UPPER[(byte & 0x0f) as usize] as _);
+	};
+	let mut sum = 0_u8;
+
+	for &byte in bytes {
+		push(byte);
+
+		sum = sum.wrapping_add(byte);
+	}
+
+	push(checksum(sum));
+	hex.push('\n');
+}
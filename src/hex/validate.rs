@@ -0,0 +1,172 @@
+// self
+use super::dehexify::HEX2DIGIT;
+use crate::prelude::*;
+
+/// The problems found in hex by [`validate`] or [`validate_array`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Validation {
+	/// Whether the hex is `0x` prefixed.
+	pub prefixed: bool,
+	/// The number of bytes after the `0x` prefix, which are expected to be digits.
+	pub digits: usize,
+	/// The length problem, [`Error::InvalidLength`] or [`Error::MismatchedLength`].
+	pub length: Option<Error>,
+	/// All the invalid characters, [`Error::InvalidCharacter`] or [`Error::InvalidByte`].
+	pub invalid_characters: Vec<Error>,
+}
+impl Validation {
+	/// Whether the hex is free of problems.
+	pub fn is_valid(&self) -> bool {
+		self.length.is_none() && self.invalid_characters.is_empty()
+	}
+
+	/// Iterate over all the problems, the length problem comes first.
+	pub fn errors(&self) -> impl Iterator<Item = &Error> {
+		self.length.iter().chain(&self.invalid_characters)
+	}
+}
+
+/// Validate hex without stopping at the first problem.
+///
+/// The whole input is scanned in a single pass. All the invalid characters are collected with their
+/// offsets against the original input, together with the length problem and the prefix status.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Error, LengthUnit};
+///
+/// let validation = array_bytes::validate("0x4g6f 66");
+///
+/// assert!(validation.prefixed);
+/// assert_eq!(
+/// 	validation.length,
+/// 	Some(Error::InvalidLength { expect: 8, actual: 7, unit: LengthUnit::Nibble })
+/// );
+/// assert_eq!(
+/// 	validation.invalid_characters,
+/// 	[
+/// 		Error::InvalidCharacter { character: 'g', index: 3, char_index: 3 },
+/// 		Error::InvalidCharacter { character: ' ', index: 6, char_index: 6 },
+/// 	]
+/// );
+/// ```
+pub fn validate<H>(hex: H) -> Validation
+where
+	H: AsRef<[u8]>,
+{
+	validate_inner(hex.as_ref(), None)
+}
+#[test]
+fn validate_should_work() {
+	assert_eq!(
+		validate("0x4c6f7665"),
+		Validation { prefixed: true, digits: 8, ..Default::default() }
+	);
+	assert!(validate("4c6f7665").is_valid());
+	assert!(validate("").is_valid());
+
+	let validation = validate("0x我爱你 z");
+	assert!(!validation.is_valid());
+	assert_eq!(validation.digits, 11);
+	assert_eq!(
+		validation.length,
+		Some(Error::InvalidLength { expect: 12, actual: 11, unit: LengthUnit::Nibble })
+	);
+	assert_eq!(
+		validation.invalid_characters,
+		[
+			Error::InvalidCharacter { character: '我', index: 2, char_index: 2 },
+			Error::InvalidCharacter { character: '爱', index: 5, char_index: 3 },
+			Error::InvalidCharacter { character: '你', index: 8, char_index: 4 },
+			Error::InvalidCharacter { character: ' ', index: 11, char_index: 5 },
+			Error::InvalidCharacter { character: 'z', index: 12, char_index: 6 },
+		]
+	);
+	assert_eq!(validation.errors().count(), 6);
+
+	assert_eq!(
+		validate(b"00\xff\xfe").invalid_characters,
+		[Error::InvalidByte { byte: 0xff, index: 2 }, Error::InvalidByte { byte: 0xfe, index: 3 }]
+	);
+}
+
+/// Validate hex against a fixed length array without stopping at the first problem.
+///
+/// Same as [`validate`], but also reports [`Error::MismatchedLength`] if the hex doesn't decode to
+/// exactly `N` bytes.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Error, LengthUnit};
+///
+/// let validation = array_bytes::validate_array::<_, 4>("0x4c6f76zz");
+///
+/// assert_eq!(
+/// 	validation.errors().collect::<Vec<_>>(),
+/// 	[
+/// 		&Error::InvalidCharacter { character: 'z', index: 8, char_index: 8 },
+/// 		&Error::InvalidCharacter { character: 'z', index: 9, char_index: 9 },
+/// 	]
+/// );
+///
+/// let validation = array_bytes::validate_array::<_, 4>("0x4c6f76");
+///
+/// assert_eq!(
+/// 	validation.length,
+/// 	Some(Error::MismatchedLength { expect: 4, actual: 3, unit: LengthUnit::Byte })
+/// );
+/// ```
+pub fn validate_array<H, const N: usize>(hex: H) -> Validation
+where
+	H: AsRef<[u8]>,
+{
+	validate_inner(hex.as_ref(), Some(N))
+}
+#[test]
+fn validate_array_should_work() {
+	assert!(validate_array::<_, 4>("0x4c6f7665").is_valid());
+	assert_eq!(
+		validate_array::<_, 4>("0x4c6f76").length,
+		Some(Error::MismatchedLength { expect: 4, actual: 3, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		validate_array::<_, 4>("0x4c6f766").length,
+		Some(Error::InvalidLength { expect: 8, actual: 7, unit: LengthUnit::Nibble })
+	);
+}
+
+fn validate_inner(hex: &[u8], expect: Option<usize>) -> Validation {
+	let prefixed = hex.starts_with(b"0x");
+	let offset = if prefixed { 2 } else { 0 };
+	let digits = hex.len() - offset;
+	let length = if !digits.is_multiple_of(2) {
+		Some(Error::InvalidLength { expect: digits + 1, actual: digits, unit: LengthUnit::Nibble })
+	} else {
+		expect.filter(|&expect| expect != digits / 2).map(|expect| Error::MismatchedLength {
+			expect,
+			actual: digits / 2,
+			unit: LengthUnit::Byte,
+		})
+	};
+	let mut invalid_characters = Vec::new();
+	let (mut i, mut char_index) = (offset, offset);
+
+	while i < hex.len() {
+		if HEX2DIGIT[hex[i] as usize].is_some() {
+			i += 1;
+		} else {
+			let e = Error::invalid_character(&hex[i..], i, char_index);
+
+			i += match &e {
+				Error::InvalidCharacter { character, .. } => character.len_utf8(),
+				_ => 1,
+			};
+
+			invalid_characters.push(e);
+		}
+
+		char_index += 1;
+	}
+
+	Validation { prefixed, digits, length, invalid_characters }
+}
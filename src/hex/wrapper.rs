@@ -0,0 +1,225 @@
+// core
+#[cfg(feature = "serde")] use core::marker::PhantomData;
+use core::{
+	fmt::{Debug, Display, Formatter, Result as FmtResult},
+	ops::Deref,
+	str::FromStr,
+};
+// alloc
+#[cfg(feature = "serde")] use alloc::format;
+// crates.io
+#[cfg(feature = "serde")]
+use serde::{
+	Deserialize, Deserializer, Serialize, Serializer,
+	de::{Error as DeError, Visitor},
+};
+// self
+use crate::{Dehexify, Hexify, prelude::*};
+
+macro_rules! impl_hex_wrapper {
+	($(#[$doc:meta])* $name:ident, $hexify:ident) => {
+		$(#[$doc])*
+		#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		pub struct $name<T>(pub T);
+		impl<T> $name<T> {
+			/// Return the inner value.
+			pub fn into_inner(self) -> T {
+				self.0
+			}
+		}
+		impl<T> From<T> for $name<T> {
+			fn from(value: T) -> Self {
+				Self(value)
+			}
+		}
+		impl<T> Deref for $name<T> {
+			type Target = T;
+
+			fn deref(&self) -> &Self::Target {
+				&self.0
+			}
+		}
+		impl<T> Display for $name<T>
+		where
+			T: Hexify,
+		{
+			fn fmt(&self, f: &mut Formatter) -> FmtResult {
+				f.write_str(&self.0.$hexify())
+			}
+		}
+		impl<T> Debug for $name<T>
+		where
+			T: Hexify,
+		{
+			fn fmt(&self, f: &mut Formatter) -> FmtResult {
+				f.write_str(&self.0.$hexify())
+			}
+		}
+		impl<T> FromStr for $name<T>
+		where
+			T: Dehexify,
+		{
+			type Err = Error;
+
+			fn from_str(hex: &str) -> Result<Self> {
+				T::dehexify(hex).map(Self)
+			}
+		}
+		#[cfg(feature = "serde")]
+		impl<T> Serialize for $name<T>
+		where
+			T: Hexify,
+		{
+			fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+			where
+				S: Serializer,
+			{
+				serializer.serialize_str(&self.0.$hexify())
+			}
+		}
+		#[cfg(feature = "serde")]
+		impl<'de, T> Deserialize<'de> for $name<T>
+		where
+			T: Dehexify,
+		{
+			fn deserialize<D>(hex: D) -> core::result::Result<Self, D::Error>
+			where
+				D: Deserializer<'de>,
+			{
+				hex.deserialize_str(HexVisitor(PhantomData)).map(Self)
+			}
+		}
+	};
+}
+impl_hex_wrapper! {
+	/// A transparent wrapper which (de)serializes, displays and parses `T` as hex.
+	///
+	/// It works without field attributes, including in nested collections. The parsing accepts any
+	/// case with or without `0x` prefix, as [`Dehexify`] does.
+	///
+	/// # Examples
+	/// ```
+	/// use array_bytes::Hex;
+	///
+	/// let hex = Hex(*b"Love");
+	///
+	/// assert_eq!(hex.to_string(), "4c6f7665");
+	/// assert_eq!(format!("{hex:?}"), "4c6f7665");
+	/// assert_eq!("0x4C6F7665".parse::<Hex<[u8; 4]>>(), Ok(hex));
+	/// assert_eq!(*hex, *b"Love");
+	/// assert_eq!(Hex::from(520_u16).to_string(), "208");
+	/// ```
+	Hex, hexify
+}
+impl_hex_wrapper! {
+	/// [`Hex`] with uppercase.
+	///
+	/// # Examples
+	/// ```
+	/// use array_bytes::HexUpper;
+	///
+	/// assert_eq!(HexUpper(*b"Love").to_string(), "4C6F7665");
+	/// ```
+	HexUpper, hexify_upper
+}
+impl_hex_wrapper! {
+	/// [`Hex`] with `0x` prefix.
+	///
+	/// # Examples
+	/// ```
+	/// use array_bytes::HexPrefixed;
+	///
+	/// assert_eq!(HexPrefixed(*b"Love").to_string(), "0x4c6f7665");
+	/// ```
+	HexPrefixed, hexify_prefixed
+}
+impl_hex_wrapper! {
+	/// [`Hex`] with `0x` prefix and uppercase.
+	///
+	/// # Examples
+	/// ```
+	/// use array_bytes::HexPrefixedUpper;
+	///
+	/// assert_eq!(HexPrefixedUpper(*b"Love").to_string(), "0x4C6F7665");
+	/// ```
+	HexPrefixedUpper, hexify_prefixed_upper
+}
+#[test]
+fn hex_wrapper_should_work() {
+	use alloc::{format, string::ToString};
+
+	assert_eq!(Hex(*b"Love").to_string(), "4c6f7665");
+	assert_eq!(HexUpper(b"Love".to_vec()).to_string(), "4C6F7665");
+	assert_eq!(HexPrefixed(5_201_314_u32).to_string(), "0x4f5da2");
+	assert_eq!(HexPrefixedUpper(5_201_314_u64).to_string(), "0x4F5DA2");
+	assert_eq!(format!("{:?}", HexPrefixed(*b"Love")), "0x4c6f7665");
+	assert_eq!(format!("{:?}", [Hex(52_u8), Hex(0)]), "[34, 0]");
+
+	assert_eq!("0x4c6f7665".parse::<Hex<[u8; 4]>>(), Ok(Hex(*b"Love")));
+	assert_eq!("4C6F7665".parse::<HexPrefixed<Vec<u8>>>(), Ok(HexPrefixed(b"Love".to_vec())));
+	assert_eq!("0x208".parse::<HexUpper<u16>>(), Ok(HexUpper(520)));
+	assert_eq!(
+		"0x4c6f766".parse::<Hex<[u8; 4]>>(),
+		Err(Error::InvalidLength { expect: 8, actual: 7, unit: LengthUnit::Nibble })
+	);
+	assert_eq!(
+		"4c6f76".parse::<Hex<[u8; 4]>>(),
+		Err(Error::MismatchedLength { expect: 4, actual: 3, unit: LengthUnit::Byte })
+	);
+
+	let hex = HexPrefixedUpper::from(*b"Love");
+
+	assert_eq!(hex.len(), 4);
+	assert_eq!(hex.into_inner(), *b"Love");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn hex_wrapper_serde_should_work() {
+	use alloc::collections::BTreeMap;
+
+	#[derive(Debug, PartialEq, Deserialize, Serialize)]
+	struct Ljf {
+		hashes: Vec<HexPrefixed<[u8; 4]>>,
+		nonces: BTreeMap<String, Hex<u64>>,
+		memo: Option<HexUpper<Vec<u8>>>,
+	}
+
+	let ljf = Ljf {
+		hashes: [HexPrefixed(*b"Love"), HexPrefixed(*b"Jane")].to_vec(),
+		nonces: [("Love".into(), Hex(520)), ("Jane".into(), Hex(0))].into_iter().collect(),
+		memo: Some(HexUpper(b"Forever".to_vec())),
+	};
+	let json = serde_json::to_string(&ljf).unwrap();
+
+	assert_eq!(
+		json,
+		r#"{"hashes":["0x4c6f7665","0x4a616e65"],"nonces":{"Jane":"0","Love":"208"},"memo":"466F7265766572"}"#
+	);
+	assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+	// Owned strings, such as the ones from `serde_json::Value`, work too.
+	assert_eq!(serde_json::from_value::<Ljf>(serde_json::from_str(&json).unwrap()).unwrap(), ljf);
+	assert!(serde_json::from_str::<Vec<Hex<[u8; 4]>>>(r#"["4c6f76"]"#).is_err());
+	assert!(serde_json::from_str::<Vec<Hex<u8>>>(r#"["100"]"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+struct HexVisitor<T>(PhantomData<T>);
+#[cfg(feature = "serde")]
+impl<T> Visitor<'_> for HexVisitor<T>
+where
+	T: Dehexify,
+{
+	type Value = T;
+
+	fn expecting(&self, f: &mut Formatter) -> FmtResult {
+		f.write_str("hex")
+	}
+
+	fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+	where
+		E: DeError,
+	{
+		T::dehexify(v).map_err(|e| E::custom(format!("{e:?}")))
+	}
+}
@@ -13,7 +13,7 @@
 //! However, this crate also offers many other utilities for Array/Bytes/Hex, each with comprehensive documentation and examples. Check them out on [docs.rs](https://docs.rs/array-bytes)!
 //!
 //! ```rust
-//! use array_bytes::{Dehexify, Error, Hexify};
+//! use array_bytes::{Dehexify, Error, Hexify, LengthUnit};
 //! use smallvec::SmallVec;
 //!
 //! // Hexify.
@@ -61,11 +61,17 @@
 //! );
 //! // `SmallVec`.
 //! assert_eq!(
-//! 	SmallVec::dehexify("0x4c6f7665204a616e6520466f7265766572").unwrap().into_vec(),
+//! 	<SmallVec<[u8; 64]>>::dehexify("0x4c6f7665204a616e6520466f7265766572").unwrap().into_vec(),
 //! 	b"Love Jane Forever".to_vec()
 //! );
-//! assert_eq!(SmallVec::dehexify("我爱你"), Err(Error::InvalidLength));
-//! assert_eq!(SmallVec::dehexify("0x我爱你"), Err(Error::InvalidLength));
+//! assert_eq!(
+//! 	<SmallVec<[u8; 64]>>::dehexify("我爱你"),
+//! 	Err(Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble })
+//! );
+//! assert_eq!(
+//! 	<SmallVec<[u8; 64]>>::dehexify("0x我爱你"),
+//! 	Err(Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble })
+//! );
 //! // `Vec`.
 //! assert_eq!(
 //! 	<Vec<u8>>::dehexify("0x4c6f7665204a616e6520466f7265766572"),
@@ -73,33 +79,85 @@
 //! );
 //! assert_eq!(
 //! 	<Vec<u8>>::dehexify("我爱你 "),
-//! 	Err(Error::InvalidCharacter { character: 'æ', index: 0 })
+//! 	Err(Error::InvalidCharacter { character: '我', index: 0, char_index: 0 })
 //! );
 //! assert_eq!(
 //! 	<Vec<u8>>::dehexify(" 我爱你"),
-//! 	Err(Error::InvalidCharacter { character: ' ', index: 0 })
+//! 	Err(Error::InvalidCharacter { character: ' ', index: 0, char_index: 0 })
 //! );
 //! ```
 
 extern crate alloc;
 
+// core
+use core::{
+	fmt::{Display, Formatter, Result as FmtResult},
+	ops::Range,
+};
+// alloc
+use alloc::boxed::Box;
+
+mod base32;
+pub use base32::*;
+
+mod base58;
+pub use base58::*;
+
+mod base64;
+pub use base64::*;
+
+mod bech32;
+pub use bech32::*;
+
+mod decimal;
+pub use decimal::*;
+
+pub mod encoding;
+pub use encoding::Encoding;
+
+mod escape;
+pub use escape::*;
+
+mod hash;
+
 mod hex;
 pub use hex::*;
 
+mod multibase;
+pub use multibase::*;
+
 mod op;
 pub use op::*;
 
-#[cfg(feature = "serde")] mod serde;
-#[cfg(feature = "serde")] pub use serde::*;
+mod packing;
+
+mod radix;
+pub use radix::*;
+
+mod report;
+pub use report::*;
+
+mod ss58;
+pub use ss58::*;
+
+#[cfg(feature = "serde")] pub mod serde;
+// Not a glob, the `with` modules stay under `serde`.
+#[cfg(feature = "serde")]
+pub use serde::{
+	as_encoded, de_bytes_destringify, de_debase64ify, de_debase64ify_unpadded, de_debase64ify_url,
+	de_debase64ify_url_unpadded, de_deescapify, de_dehexify, de_dehexify_or_bytes, de_dess58ify,
+	dehexify_array_then_deserialize_into, dehexify_vec_then_deserialize_into, ser_base64ify,
+	ser_base64ify_unpadded, ser_base64ify_url, ser_base64ify_url_unpadded, ser_bytes_stringify,
+	ser_escapify, ser_hexify, ser_hexify_or_bytes, ser_hexify_prefixed,
+	ser_hexify_prefixed_or_bytes, ser_hexify_prefixed_upper, ser_hexify_upper, ser_ss58ify,
+};
 
 mod prelude {
 	pub use alloc::{string::String, vec::Vec};
 
 	pub use smallvec::SmallVec;
 
-	pub use crate::{Error, Result};
-
-	pub(crate) use crate::op;
+	pub use crate::{Error, LengthUnit, Result};
 
 	#[cfg(test)]
 	mod test {
@@ -108,6 +166,7 @@ mod prelude {
 		use criterion as _;
 		use faster_hex as _;
 		use hex_crate as _;
+		use postcard as _;
 		use rustc_hex as _;
 		use serde as _;
 		use serde_json as _;
@@ -147,10 +206,173 @@ pub enum Error {
 	#[error(transparent)]
 	Utf8Error(#[from] core::str::Utf8Error),
 
-	#[error("length must not be odd")]
-	InvalidLength,
-	#[error("invalid character({character}) at index({index})")]
-	InvalidCharacter { character: char, index: usize },
-	#[error("mismatched length, expected {expect}")]
-	MismatchedLength { expect: usize },
+	#[error("invalid length, expected {expect} {unit}, got {actual}")]
+	InvalidLength { expect: usize, actual: usize, unit: LengthUnit },
+	#[error("invalid character({character}) at index({index}), char index({char_index})")]
+	InvalidCharacter { character: char, index: usize, char_index: usize },
+	#[error("invalid byte({byte:#04x}) at index({index})")]
+	InvalidByte { byte: u8, index: usize },
+	#[error("mismatched length, expected {expect} {unit}, got {actual}")]
+	MismatchedLength { expect: usize, actual: usize, unit: LengthUnit },
+	#[error("capacity overflow, capacity is {capacity} bytes, got {length}")]
+	CapacityOverflow { capacity: usize, length: usize },
+	#[error("invalid element({element}), {source}")]
+	InvalidElement { element: usize, source: Box<Error> },
+	#[error("invalid record at index({index})")]
+	InvalidRecord { index: usize },
+	#[error("invalid checksum at index({index}), expected {expect:#04x}, got {actual:#04x}")]
+	InvalidChecksum { expect: u8, actual: u8, index: usize },
+	#[error("overlapping record at index({index}), address({address:#010x}) is already written")]
+	OverlappingRecord { address: u32, index: usize },
+	#[error("mismatched checksum, expected {expect:#010x}, got {actual:#010x}")]
+	MismatchedChecksum { expect: u32, actual: u32 },
+	#[error("invalid prefix({prefix})")]
+	InvalidPrefix { prefix: u16 },
+	#[error("invalid padding at index({index})")]
+	InvalidPadding { index: usize },
+	#[error("invalid trailing bits at index({index}), the unused bits must be zero")]
+	InvalidTrailingBits { index: usize },
+	#[error("invalid separator at index({index})")]
+	InvalidSeparator { index: usize },
+	#[error("unknown multibase code({code})")]
+	UnknownMultibase { code: char },
+	#[error("invalid byte digits at index({index})")]
+	InvalidByteDigits { index: usize },
+	#[error("invalid escape at index({index})")]
+	InvalidEscape { index: usize },
+	#[error("insufficient length, expected at least {min} {unit}, got {actual}")]
+	InsufficientLength { min: usize, actual: usize, unit: LengthUnit },
+	#[error("invalid human-readable part length({length})")]
+	InvalidHrpLength { length: usize },
+	#[error("invalid decimals({decimals}), expected at most {max}")]
+	InvalidDecimals { decimals: usize, max: usize },
+	#[error("invalid thousands separator({separator}), it must not be a digit or `.`")]
+	InvalidThousandsSeparator { separator: char },
+}
+impl Error {
+	/// Return the byte range of the input to highlight, if the error points at a position.
+	///
+	/// # Examples
+	/// ```
+	/// use array_bytes::Dehexify;
+	///
+	/// let hex = "0x我爱你 ";
+	/// let e = <Vec<u8>>::dehexify(hex).unwrap_err();
+	///
+	/// assert_eq!(e.span(), Some(2..5));
+	/// assert_eq!(&hex[e.span().unwrap()], "我");
+	/// ```
+	pub fn span(&self) -> Option<Range<usize>> {
+		match self {
+			Self::InvalidCharacter { character, index, .. } =>
+				Some(*index..index + character.len_utf8()),
+			Self::InvalidByte { index, .. } => Some(*index..index + 1),
+			Self::InvalidElement { source, .. } => source.span(),
+			Self::InvalidRecord { index }
+			| Self::OverlappingRecord { index, .. }
+			| Self::InvalidPadding { index }
+			| Self::InvalidTrailingBits { index }
+			| Self::InvalidSeparator { index }
+			| Self::InvalidByteDigits { index }
+			| Self::InvalidEscape { index } => Some(*index..index + 1),
+			Self::InvalidChecksum { index, .. } => Some(*index..index + 2),
+			Self::UnknownMultibase { code } => Some(0..code.len_utf8()),
+			_ => None,
+		}
+	}
+
+	/// Move the position of the error by the given offsets.
+	pub(crate) fn rebase(self, index: usize, char_index: usize) -> Self {
+		match self {
+			Self::InvalidCharacter { character, index: i, char_index: c } =>
+				Self::InvalidCharacter { character, index: index + i, char_index: char_index + c },
+			Self::InvalidByte { byte, index: i } => Self::InvalidByte { byte, index: index + i },
+			Self::InvalidPadding { index: i } => Self::InvalidPadding { index: index + i },
+			Self::InvalidTrailingBits { index: i } =>
+				Self::InvalidTrailingBits { index: index + i },
+			e => e,
+		}
+	}
+
+	/// Build an invalid character error from the bytes starting at the invalid one.
+	///
+	/// If they start with a valid UTF-8 character, that character is reported. Otherwise, the raw
+	/// byte is reported.
+	pub(crate) fn invalid_character(bytes: &[u8], index: usize, char_index: usize) -> Self {
+		let head = &bytes[..bytes.len().min(4)];
+
+		match head.utf8_chunks().next().and_then(|chunk| chunk.valid().chars().next()) {
+			Some(character) => Self::InvalidCharacter { character, index, char_index },
+			None => Self::InvalidByte { byte: bytes[0], index },
+		}
+	}
+}
+#[test]
+fn span_should_work() {
+	assert_eq!(
+		Error::InvalidCharacter { character: '我', index: 2, char_index: 2 }.span(),
+		Some(2..5)
+	);
+	assert_eq!(Error::InvalidByte { byte: 0xff, index: 2 }.span(), Some(2..3));
+	assert_eq!(
+		Error::InvalidElement {
+			element: 1,
+			source: Box::new(Error::InvalidByte { byte: 0xff, index: 2 })
+		}
+		.span(),
+		Some(2..3)
+	);
+	assert_eq!(
+		Error::InvalidLength { expect: 2, actual: 1, unit: LengthUnit::Nibble }.span(),
+		None
+	);
+	assert_eq!(Error::InvalidChecksum { expect: 0, actual: 1, index: 9 }.span(), Some(9..11));
+}
+
+/// The unit of the lengths in [`Error::InvalidLength`], [`Error::MismatchedLength`] and
+/// [`Error::InsufficientLength`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthUnit {
+	/// Bytes.
+	Byte,
+	/// Nibbles, the hex digits.
+	Nibble,
+	/// Characters of the input.
+	Character,
+	/// Elements of a generic slice.
+	Element,
+}
+impl Display for LengthUnit {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		match self {
+			Self::Byte => f.write_str("bytes"),
+			Self::Nibble => f.write_str("nibbles"),
+			Self::Character => f.write_str("characters"),
+			Self::Element => f.write_str("elements"),
+		}
+	}
+}
+#[test]
+fn length_unit_should_work() {
+	assert_eq!(
+		alloc::format!(
+			"{}",
+			Error::MismatchedLength { expect: 32, actual: 31, unit: LengthUnit::Byte }
+		),
+		"mismatched length, expected 32 bytes, got 31"
+	);
+	assert_eq!(
+		alloc::format!(
+			"{}",
+			Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble }
+		),
+		"invalid length, expected 10 nibbles, got 9"
+	);
+	assert_eq!(
+		alloc::format!(
+			"{}",
+			Error::InsufficientLength { min: 4, actual: 3, unit: LengthUnit::Byte }
+		),
+		"insufficient length, expected at least 4 bytes, got 3"
+	);
 }
@@ -0,0 +1,372 @@
+// self
+use crate::{
+	Base32ify, Base58Alphabet, Base58ify, Base64ify, Debase32ify, Debase58ify, Debase64ify,
+	Dehexify, Hexify, prelude::*,
+};
+
+/// The multibase encodings, identified by their one character codes.
+///
+/// Only the encodings this crate implements are supported, see the
+/// [multibase table](https://github.com/multiformats/multibase/blob/master/multibase.csv).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Multibase {
+	/// `f`, lowercase hex.
+	Base16,
+	/// `F`, uppercase hex.
+	Base16Upper,
+	/// `b`, lowercase RFC 4648 Base32 without padding.
+	Base32,
+	/// `B`, uppercase RFC 4648 Base32 without padding.
+	Base32Upper,
+	/// `c`, lowercase RFC 4648 Base32 with padding.
+	Base32Pad,
+	/// `C`, uppercase RFC 4648 Base32 with padding.
+	Base32PadUpper,
+	/// `v`, lowercase RFC 4648 extended hex Base32 without padding.
+	Base32Hex,
+	/// `V`, uppercase RFC 4648 extended hex Base32 without padding.
+	Base32HexUpper,
+	/// `t`, lowercase RFC 4648 extended hex Base32 with padding.
+	Base32HexPad,
+	/// `T`, uppercase RFC 4648 extended hex Base32 with padding.
+	Base32HexPadUpper,
+	/// `z`, Base58 with Bitcoin's alphabet.
+	Base58Btc,
+	/// `Z`, Base58 with Flickr's alphabet.
+	Base58Flickr,
+	/// `m`, standard Base64 without padding.
+	Base64,
+	/// `M`, standard Base64 with padding.
+	Base64Pad,
+	/// `u`, URL-safe Base64 without padding.
+	Base64Url,
+	/// `U`, URL-safe Base64 with padding.
+	Base64UrlPad,
+}
+impl Multibase {
+	/// Return the code of the encoding.
+	pub fn code(self) -> char {
+		match self {
+			Self::Base16 => 'f',
+			Self::Base16Upper => 'F',
+			Self::Base32 => 'b',
+			Self::Base32Upper => 'B',
+			Self::Base32Pad => 'c',
+			Self::Base32PadUpper => 'C',
+			Self::Base32Hex => 'v',
+			Self::Base32HexUpper => 'V',
+			Self::Base32HexPad => 't',
+			Self::Base32HexPadUpper => 'T',
+			Self::Base58Btc => 'z',
+			Self::Base58Flickr => 'Z',
+			Self::Base64 => 'm',
+			Self::Base64Pad => 'M',
+			Self::Base64Url => 'u',
+			Self::Base64UrlPad => 'U',
+		}
+	}
+
+	/// Return the encoding of the code, if it's supported.
+	pub fn from_code(code: char) -> Option<Self> {
+		Some(match code {
+			'f' => Self::Base16,
+			'F' => Self::Base16Upper,
+			'b' => Self::Base32,
+			'B' => Self::Base32Upper,
+			'c' => Self::Base32Pad,
+			'C' => Self::Base32PadUpper,
+			'v' => Self::Base32Hex,
+			'V' => Self::Base32HexUpper,
+			't' => Self::Base32HexPad,
+			'T' => Self::Base32HexPadUpper,
+			'z' => Self::Base58Btc,
+			'Z' => Self::Base58Flickr,
+			'm' => Self::Base64,
+			'M' => Self::Base64Pad,
+			'u' => Self::Base64Url,
+			'U' => Self::Base64UrlPad,
+			_ => None?,
+		})
+	}
+
+	// The lowercase Base32 encodings, which are decoded in uppercase.
+	fn lowercase(self) -> bool {
+		matches!(self, Self::Base32 | Self::Base32Pad | Self::Base32Hex | Self::Base32HexPad)
+	}
+}
+#[test]
+fn multibase_should_work() {
+	for base in [
+		Multibase::Base16,
+		Multibase::Base16Upper,
+		Multibase::Base32,
+		Multibase::Base32Upper,
+		Multibase::Base32Pad,
+		Multibase::Base32PadUpper,
+		Multibase::Base32Hex,
+		Multibase::Base32HexUpper,
+		Multibase::Base32HexPad,
+		Multibase::Base32HexPadUpper,
+		Multibase::Base58Btc,
+		Multibase::Base58Flickr,
+		Multibase::Base64,
+		Multibase::Base64Pad,
+		Multibase::Base64Url,
+		Multibase::Base64UrlPad,
+	] {
+		assert_eq!(Multibase::from_code(base.code()), Some(base));
+	}
+
+	assert_eq!(Multibase::from_code('k'), None);
+	assert_eq!(Multibase::from_code('我'), None);
+}
+
+/// Multibaseify `Self` with the given encoding, the code is prepended.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Multibase, Multibaseify};
+///
+/// // `[u8; N]`.
+/// assert_eq!(b"Love".multibaseify(Multibase::Base16), "f4c6f7665");
+/// // `&[u8]`.
+/// assert_eq!(b"Love".as_slice().multibaseify(Multibase::Base32), "bjrxxmzi");
+/// // `Vec<u8>`.
+/// assert_eq!(b"Love".to_vec().multibaseify(Multibase::Base58Btc), "z2xKVt8");
+/// assert_eq!(b"Love".to_vec().multibaseify(Multibase::Base64Pad), "MTG92ZQ==");
+/// ```
+pub trait Multibaseify {
+	/// Multibaseify `Self` with the given encoding.
+	fn multibaseify(&self, base: Multibase) -> String;
+}
+macro_rules! multibaseify_bytes_fns {
+	() => {
+		fn multibaseify(&self, base: Multibase) -> String {
+			multibaseify(self, base)
+		}
+	};
+}
+impl<const N: usize> Multibaseify for [u8; N] {
+	multibaseify_bytes_fns! {}
+}
+impl Multibaseify for [u8] {
+	multibaseify_bytes_fns! {}
+}
+impl Multibaseify for Vec<u8> {
+	multibaseify_bytes_fns! {}
+}
+#[test]
+fn multibaseify_should_work() {
+	// The multibase spec's test vectors of `yes mani !`.
+	let bytes = b"yes mani !";
+
+	assert_eq!(bytes.multibaseify(Multibase::Base16), "f796573206d616e692021");
+	assert_eq!(bytes.multibaseify(Multibase::Base16Upper), "F796573206D616E692021");
+	assert_eq!(bytes.multibaseify(Multibase::Base32), "bpfsxgidnmfxgsibb");
+	assert_eq!(bytes.multibaseify(Multibase::Base32Upper), "BPFSXGIDNMFXGSIBB");
+	assert_eq!(bytes.multibaseify(Multibase::Base32Pad), "cpfsxgidnmfxgsibb");
+	assert_eq!(bytes.multibaseify(Multibase::Base32PadUpper), "CPFSXGIDNMFXGSIBB");
+	assert_eq!(bytes.multibaseify(Multibase::Base32Hex), "vf5in683dc5n6i811");
+	assert_eq!(bytes.multibaseify(Multibase::Base32HexUpper), "VF5IN683DC5N6I811");
+	assert_eq!(bytes.multibaseify(Multibase::Base32HexPad), "tf5in683dc5n6i811");
+	assert_eq!(bytes.multibaseify(Multibase::Base32HexPadUpper), "TF5IN683DC5N6I811");
+	assert_eq!(bytes.multibaseify(Multibase::Base58Btc), "z7paNL19xttacUY");
+	assert_eq!(bytes.multibaseify(Multibase::Base58Flickr), "Z7Pznk19XTTzBtx");
+	assert_eq!(bytes.multibaseify(Multibase::Base64), "meWVzIG1hbmkgIQ");
+	assert_eq!(bytes.multibaseify(Multibase::Base64Pad), "MeWVzIG1hbmkgIQ==");
+	assert_eq!(bytes.multibaseify(Multibase::Base64Url), "ueWVzIG1hbmkgIQ");
+	assert_eq!(bytes.multibaseify(Multibase::Base64UrlPad), "UeWVzIG1hbmkgIQ==");
+	assert_eq!([].multibaseify(Multibase::Base64), "m");
+}
+
+/// Demultibaseify the given multibase to the encoding and `Self`, dispatching on the code.
+///
+/// An unsupported code is reported with [`Error::UnknownMultibase`], and an empty input with
+/// [`Error::InsufficientLength`]. The rest is decoded as strictly as the underlying decoder does,
+/// the hex must not be prefixed with `0x`, and the error indexes point into the whole input.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Demultibaseify, Error, Multibase};
+///
+/// // `[u8; N]`.
+/// assert_eq!(<[u8; 4]>::demultibaseify("f4c6f7665"), Ok((Multibase::Base16, *b"Love")));
+/// // `Vec<u8>`.
+/// assert_eq!(<Vec<u8>>::demultibaseify("bjrxxmzi"), Ok((Multibase::Base32, b"Love".to_vec())));
+/// assert_eq!(<Vec<u8>>::demultibaseify("z2xKVt8"), Ok((Multibase::Base58Btc, b"Love".to_vec())));
+/// assert_eq!(<Vec<u8>>::demultibaseify("k2jx1"), Err(Error::UnknownMultibase { code: 'k' }));
+/// assert_eq!(<Vec<u8>>::demultibaseify("MTG92ZQ="), Err(Error::InvalidPadding { index: 8 }));
+/// ```
+pub trait Demultibaseify
+where
+	Self: Sized,
+{
+	/// Demultibaseify the encoding and `Self` from the multibase.
+	fn demultibaseify<B>(multibase: B) -> Result<(Multibase, Self)>
+	where
+		B: AsRef<[u8]>;
+}
+impl<const N: usize> Demultibaseify for [u8; N] {
+	fn demultibaseify<B>(multibase: B) -> Result<(Multibase, Self)>
+	where
+		B: AsRef<[u8]>,
+	{
+		demultibaseify(multibase.as_ref())
+	}
+}
+impl Demultibaseify for Vec<u8> {
+	fn demultibaseify<B>(multibase: B) -> Result<(Multibase, Self)>
+	where
+		B: AsRef<[u8]>,
+	{
+		demultibaseify(multibase.as_ref())
+	}
+}
+#[test]
+fn demultibaseify_should_work() {
+	for multibase in [
+		"f796573206d616e692021",
+		"F796573206D616E692021",
+		"bpfsxgidnmfxgsibb",
+		"BPFSXGIDNMFXGSIBB",
+		"cpfsxgidnmfxgsibb",
+		"CPFSXGIDNMFXGSIBB",
+		"vf5in683dc5n6i811",
+		"VF5IN683DC5N6I811",
+		"tf5in683dc5n6i811",
+		"TF5IN683DC5N6I811",
+		"z7paNL19xttacUY",
+		"Z7Pznk19XTTzBtx",
+		"meWVzIG1hbmkgIQ",
+		"MeWVzIG1hbmkgIQ==",
+		"ueWVzIG1hbmkgIQ",
+		"UeWVzIG1hbmkgIQ==",
+	] {
+		assert_eq!(<[u8; 10]>::demultibaseify(multibase).map(|(_, b)| b), Ok(*b"yes mani !"));
+	}
+
+	assert_eq!(<Vec<u8>>::demultibaseify("m"), Ok((Multibase::Base64, Vec::new())));
+	assert_eq!(
+		<Vec<u8>>::demultibaseify("cpfsxgidnmfxgsibb"),
+		Ok((Multibase::Base32Pad, b"yes mani !".to_vec()))
+	);
+
+	// Code.
+	assert_eq!(
+		<Vec<u8>>::demultibaseify(""),
+		Err(Error::InsufficientLength { min: 1, actual: 0, unit: LengthUnit::Character })
+	);
+	assert_eq!(<Vec<u8>>::demultibaseify("9"), Err(Error::UnknownMultibase { code: '9' }));
+	assert_eq!(<Vec<u8>>::demultibaseify("我"), Err(Error::UnknownMultibase { code: '我' }));
+	assert_eq!(
+		<Vec<u8>>::demultibaseify(b"\xff"),
+		Err(Error::InvalidByte { byte: 0xff, index: 0 })
+	);
+	// Body.
+	assert_eq!(
+		<Vec<u8>>::demultibaseify("f0x4c6f7665"),
+		Err(Error::InvalidCharacter { character: 'x', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		<Vec<u8>>::demultibaseify("f4C6f7665"),
+		Err(Error::InvalidCharacter { character: 'C', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		<Vec<u8>>::demultibaseify("bjrxXmzi"),
+		Err(Error::InvalidCharacter { character: 'X', index: 4, char_index: 4 })
+	);
+	assert_eq!(
+		<Vec<u8>>::demultibaseify("vjrxxmzi"),
+		Err(Error::InvalidCharacter { character: 'x', index: 3, char_index: 3 })
+	);
+	assert_eq!(
+		<Vec<u8>>::demultibaseify("z2xKVtl"),
+		Err(Error::InvalidCharacter { character: 'l', index: 6, char_index: 6 })
+	);
+	assert_eq!(<Vec<u8>>::demultibaseify("bjrxxmzj"), Err(Error::InvalidTrailingBits { index: 7 }));
+	assert_eq!(<Vec<u8>>::demultibaseify("cjrxxmzi"), Err(Error::InvalidPadding { index: 8 }));
+	assert_eq!(
+		<[u8; 3]>::demultibaseify("f4c6f7665"),
+		Err(Error::MismatchedLength { expect: 3, actual: 4, unit: LengthUnit::Byte })
+	);
+}
+
+fn multibaseify(bytes: &[u8], base: Multibase) -> String {
+	let mut multibase = String::from(base.code());
+
+	multibase.push_str(&match base {
+		Multibase::Base16 => bytes.hexify(),
+		Multibase::Base16Upper => bytes.hexify_upper(),
+		Multibase::Base32 | Multibase::Base32Upper => bytes.base32ify_unpadded(),
+		Multibase::Base32Pad | Multibase::Base32PadUpper => bytes.base32ify(),
+		Multibase::Base32Hex | Multibase::Base32HexUpper => bytes.base32ify_hex_unpadded(),
+		Multibase::Base32HexPad | Multibase::Base32HexPadUpper => bytes.base32ify_hex(),
+		Multibase::Base58Btc => bytes.base58ify(),
+		Multibase::Base58Flickr => bytes.base58ify_with(Base58Alphabet::Flickr),
+		Multibase::Base64 => bytes.base64ify_unpadded(),
+		Multibase::Base64Pad => bytes.base64ify(),
+		Multibase::Base64Url => bytes.base64ify_url_unpadded(),
+		Multibase::Base64UrlPad => bytes.base64ify_url(),
+	});
+
+	if base.lowercase() {
+		multibase.make_ascii_lowercase();
+	}
+
+	multibase
+}
+
+fn demultibaseify<T>(multibase: &[u8]) -> Result<(Multibase, T)>
+where
+	T: Dehexify + Debase32ify + Debase58ify + Debase64ify,
+{
+	if multibase.is_empty() {
+		Err(Error::InsufficientLength { min: 1, actual: 0, unit: LengthUnit::Character })?;
+	}
+
+	// Read the first character, or the invalid byte.
+	let base = match Error::invalid_character(multibase, 0, 0) {
+		Error::InvalidCharacter { character, .. } =>
+			Multibase::from_code(character).ok_or(Error::UnknownMultibase { code: character })?,
+		e => Err(e)?,
+	};
+	// The code is supported, so it's one byte; qed.
+	let body = &multibase[1..];
+
+	if matches!(base, Multibase::Base16 | Multibase::Base16Upper) && body.starts_with(b"0x") {
+		Err(Error::InvalidCharacter { character: 'x', index: 2, char_index: 2 })?;
+	}
+	if base.lowercase()
+		&& let Some(i) = body.iter().position(u8::is_ascii_uppercase)
+	{
+		Err(Error::InvalidCharacter { character: body[i] as _, index: 1 + i, char_index: 1 + i })?;
+	}
+
+	let this = match base {
+		Multibase::Base16 => T::dehexify_lower(body),
+		Multibase::Base16Upper => T::dehexify_upper(body),
+		Multibase::Base32 => T::debase32ify_unpadded(body.to_ascii_uppercase()),
+		Multibase::Base32Upper => T::debase32ify_unpadded(body),
+		Multibase::Base32Pad => T::debase32ify(body.to_ascii_uppercase()),
+		Multibase::Base32PadUpper => T::debase32ify(body),
+		Multibase::Base32Hex => T::debase32ify_hex_unpadded(body.to_ascii_uppercase()),
+		Multibase::Base32HexUpper => T::debase32ify_hex_unpadded(body),
+		Multibase::Base32HexPad => T::debase32ify_hex(body.to_ascii_uppercase()),
+		Multibase::Base32HexPadUpper => T::debase32ify_hex(body),
+		Multibase::Base58Btc => T::debase58ify(body),
+		Multibase::Base58Flickr => T::debase58ify_with(body, Base58Alphabet::Flickr),
+		Multibase::Base64 => T::debase64ify_unpadded(body),
+		Multibase::Base64Pad => T::debase64ify(body),
+		Multibase::Base64Url => T::debase64ify_url_unpadded(body),
+		Multibase::Base64UrlPad => T::debase64ify_url(body),
+	}
+	.map_err(|e| match e {
+		// The uppercased body has no lowercase letters, the original is reported.
+		Error::InvalidCharacter { character, index, char_index } if base.lowercase() =>
+			Error::InvalidCharacter { character: character.to_ascii_lowercase(), index, char_index },
+		e => e,
+	})
+	.map_err(|e| e.rebase(1, 1))?;
+
+	Ok((base, this))
+}
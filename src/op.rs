@@ -5,7 +5,9 @@ mod vec;
 pub use vec::*;
 
 // core
-use core::cmp::Ordering;
+use core::{cmp::Ordering, mem};
+// self
+use crate::LengthUnit;
 
 /// Prefixes the given element to the given array/slice/vector to make it a fixed-size array of
 /// length `N`.
@@ -93,3 +95,8 @@ where
 		},
 	}
 }
+
+// The unit to report the length of `[T]` in, the one-byte elements are counted as bytes.
+fn length_unit<T>() -> LengthUnit {
+	if mem::size_of::<T>() == 1 { LengthUnit::Byte } else { LengthUnit::Element }
+}
@@ -15,12 +15,24 @@ pub fn slice2array<T, const N: usize>(slice: &[T]) -> Result<[T; N]>
 where
 	T: Copy,
 {
-	slice.try_into().map_err(|_| Error::MismatchedLength { expect: N })
+	slice.try_into().map_err(|_| Error::MismatchedLength {
+		expect: N,
+		actual: slice.len(),
+		unit: super::length_unit::<T>(),
+	})
 }
 
 #[test]
 fn slice2array_should_work() {
-	assert_eq!(slice2array::<_, 8>(&[0; 8]), Ok([0; 8]));
+	assert_eq!(slice2array::<_, 8>(&[0_u8; 8]), Ok([0; 8]));
+	assert_eq!(
+		slice2array::<_, 8>(&[0_u8; 9]),
+		Err(Error::MismatchedLength { expect: 8, actual: 9, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		slice2array::<_, 2>(&[0_u64; 1]),
+		Err(Error::MismatchedLength { expect: 2, actual: 1, unit: LengthUnit::Element })
+	);
 }
 
 /// Convert `&[T]` to `&[T; N]`.
@@ -36,7 +48,11 @@ pub fn slice2array_ref<T, const N: usize>(slice: &[T]) -> Result<&[T; N]>
 where
 	T: Copy,
 {
-	slice.try_into().map_err(|_| Error::MismatchedLength { expect: N })
+	slice.try_into().map_err(|_| Error::MismatchedLength {
+		expect: N,
+		actual: slice.len(),
+		unit: super::length_unit::<T>(),
+	})
 }
 
 /// Convert `&[T]` to `V` where `V: From<[T; N]>`.
@@ -66,4 +82,8 @@ where
 #[test]
 fn slice_n_into_should_work() {
 	assert_eq!(slice_n_into::<u8, Ljfn, 17>(b"Love Jane Forever"), Ok(Ljfn(*b"Love Jane Forever")));
+	assert_eq!(
+		slice_n_into::<u8, Ljfn, 17>(b"Love Jane"),
+		Err(Error::MismatchedLength { expect: 17, actual: 9, unit: LengthUnit::Byte })
+	);
 }
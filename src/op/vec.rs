@@ -8,11 +8,23 @@ use crate::prelude::*;
 /// assert_eq!(array_bytes::vec2array::<_, 8>(vec![0; 8]), Ok([0; 8]));
 /// ```
 pub fn vec2array<T, const N: usize>(vec: Vec<T>) -> Result<[T; N]> {
-	vec.try_into().map_err(|_| Error::MismatchedLength { expect: N })
+	vec.try_into().map_err(|v: Vec<T>| Error::MismatchedLength {
+		expect: N,
+		actual: v.len(),
+		unit: super::length_unit::<T>(),
+	})
 }
 #[test]
 fn vec2array_should_work() {
-	assert_eq!(vec2array::<_, 8>(alloc::vec![0; 8]), Ok([0; 8]));
+	assert_eq!(vec2array::<_, 8>(alloc::vec![0_u8; 8]), Ok([0; 8]));
+	assert_eq!(
+		vec2array::<_, 8>(alloc::vec![0_u8; 7]),
+		Err(Error::MismatchedLength { expect: 8, actual: 7, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		vec2array::<_, 2>(alloc::vec![0_u64; 1]),
+		Err(Error::MismatchedLength { expect: 2, actual: 1, unit: LengthUnit::Element })
+	);
 }
 
 /// Convert `Vec<T>` to `V` where `V: From<[T; N]>`.
@@ -45,4 +57,8 @@ fn vec_n_into_should_work() {
 		vec_n_into::<u8, Ljfn, 17>(b"Love Jane Forever".to_vec()),
 		Ok(Ljfn(*b"Love Jane Forever"))
 	);
+	assert_eq!(
+		vec_n_into::<u8, Ljfn, 17>(b"Love Jane".to_vec()),
+		Err(Error::MismatchedLength { expect: 17, actual: 9, unit: LengthUnit::Byte })
+	);
 }
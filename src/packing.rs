@@ -0,0 +1,192 @@
+//! The bit packing shared by Base32 and Base64, whose digits take a fixed number of bits each.
+
+// core
+use core::iter;
+// self
+use crate::prelude::*;
+
+/// A power of two base with its alphabet and padding.
+#[derive(Clone, Copy)]
+pub(crate) struct Packing {
+	/// The bits of a digit, `5` for Base32 and `6` for Base64.
+	pub(crate) bits: usize,
+	pub(crate) chars: &'static [u8],
+	pub(crate) table: &'static [Option<u8>; 256],
+	pub(crate) padded: bool,
+}
+impl Packing {
+	// The digits of a group, the shortest run of digits which ends on a byte boundary.
+	fn group_digits(self) -> usize {
+		8 / gcd(self.bits, 8)
+	}
+
+	// The bytes of a group.
+	fn group_bytes(self) -> usize {
+		self.bits / gcd(self.bits, 8)
+	}
+
+	// The digits to encode the given bytes, without padding.
+	fn digits_of(self, bytes: usize) -> usize {
+		(bytes * 8).div_ceil(self.bits)
+	}
+
+	// Whether the given digits, less than a group, form the end of some bytes.
+	fn is_tail(self, digits: usize) -> bool {
+		let bytes = digits * self.bits / 8;
+
+		digits == 0 || (bytes != 0 && self.digits_of(bytes) == digits)
+	}
+
+	pub(crate) fn encode(self, bytes: &[u8]) -> String {
+		let (group_digits, group_bytes) = (self.group_digits(), self.group_bytes());
+		let mask = (1 << self.bits) - 1;
+		let digit = |n: u64, i: usize| self.chars[(n >> (i * self.bits)) as usize & mask] as char;
+		let mut encoded = String::with_capacity(bytes.len().div_ceil(group_bytes) * group_digits);
+
+		for chunk in bytes.chunks(group_bytes) {
+			// The bytes are aligned to the start of the group.
+			let n = chunk
+				.iter()
+				.enumerate()
+				.fold(0, |n, (i, b)| n | (*b as u64) << (8 * (group_bytes - 1 - i)));
+			let digits = self.digits_of(chunk.len());
+
+			encoded.extend((0..group_digits).rev().take(digits).map(|i| digit(n, i)));
+
+			if self.padded {
+				encoded.extend(iter::repeat_n('=', group_digits - digits));
+			}
+		}
+
+		encoded
+	}
+
+	pub(crate) fn decode_array<const N: usize>(self, encoded: &[u8]) -> Result<[u8; N]> {
+		let mut array = [0; N];
+
+		self.decode_slice_mut(encoded, &mut array)?;
+
+		Ok(array)
+	}
+
+	pub(crate) fn decode_vec(self, encoded: &[u8]) -> Result<Vec<u8>> {
+		let digits = self.strip_padding(encoded)?;
+		let mut bytes = alloc::vec![0; self.decoded_len(digits.len())];
+
+		self.decode_into(digits, &mut bytes)?;
+
+		Ok(bytes)
+	}
+
+	pub(crate) fn decode_slice_mut<'a>(
+		self,
+		encoded: &[u8],
+		slice_src: &'a mut [u8],
+	) -> Result<&'a [u8]> {
+		let digits = self.strip_padding(encoded)?;
+		let len = self.decoded_len(digits.len());
+
+		if len != slice_src.len() {
+			Err(Error::MismatchedLength {
+				expect: slice_src.len(),
+				actual: len,
+				unit: LengthUnit::Byte,
+			})?;
+		}
+
+		self.decode_into(digits, slice_src)?;
+
+		Ok(slice_src)
+	}
+
+	// Strip the padding and return the digits, whose last group always ends some bytes.
+	fn strip_padding(self, encoded: &[u8]) -> Result<&[u8]> {
+		let group_digits = self.group_digits();
+
+		if !self.padded {
+			if !self.is_tail(encoded.len() % group_digits) {
+				// The last group is incomplete.
+				Err(Error::InvalidPadding { index: encoded.len() - 1 })?;
+			}
+
+			return Ok(encoded);
+		}
+		if !encoded.len().is_multiple_of(group_digits) {
+			Err(Error::InvalidPadding { index: encoded.len() })?;
+		}
+
+		let padding = encoded.iter().rev().take_while(|c| **c == b'=').count();
+
+		if padding >= group_digits || !self.is_tail((group_digits - padding) % group_digits) {
+			Err(Error::InvalidPadding { index: encoded.len() - padding })?;
+		}
+
+		Ok(&encoded[..encoded.len() - padding])
+	}
+
+	fn decoded_len(self, digits: usize) -> usize {
+		let group_digits = self.group_digits();
+
+		digits / group_digits * self.group_bytes() + digits % group_digits * self.bits / 8
+	}
+
+	// The length of the bytes must be checked by the caller.
+	fn decode_into(self, digits: &[u8], bytes: &mut [u8]) -> Result<()> {
+		let (group_digits, group_bytes) = (self.group_digits(), self.group_bytes());
+		let digit = |i: usize| {
+			// The characters before `i` are all checked, they are ASCII; qed.
+			self.table[digits[i] as usize]
+				.ok_or_else(|| Error::invalid_character(&digits[i..], i, i))
+		};
+
+		for (i, chunk) in bytes.chunks_mut(group_bytes).enumerate() {
+			let start = i * group_digits;
+			let len = self.digits_of(chunk.len());
+			let n = (0..len)
+				.try_fold(0, |n, j| Ok::<_, Error>(n << self.bits | digit(start + j)? as u64))?;
+			// The unused bits of the last digit, only the last group may have them.
+			let unused = len * self.bits - chunk.len() * 8;
+
+			if n & ((1 << unused) - 1) != 0 {
+				Err(Error::InvalidTrailingBits { index: start + len - 1 })?;
+			}
+
+			let n = n >> unused;
+			let last = chunk.len() - 1;
+
+			for (j, byte) in chunk.iter_mut().enumerate() {
+				*byte = (n >> (8 * (last - j))) as u8;
+			}
+		}
+
+		Ok(())
+	}
+}
+#[test]
+fn packing_should_work() {
+	use crate::{Base32Variant, Base64Variant};
+
+	let bases = [
+		Base32Variant::Standard.packing(),
+		Base32Variant::Crockford.packing(),
+		Base64Variant::Standard.packing(),
+		Base64Variant::UrlUnpadded.packing(),
+	];
+
+	for packing in bases {
+		for len in 0..=16 {
+			let bytes = (0..len as u8).map(|b| b.wrapping_mul(0x9d)).collect::<Vec<_>>();
+			let encoded = packing.encode(&bytes);
+
+			assert_eq!(packing.decode_vec(encoded.as_bytes()), Ok(bytes.clone()));
+			assert_eq!(
+				packing.decoded_len(packing.strip_padding(encoded.as_bytes()).unwrap().len()),
+				len
+			);
+		}
+	}
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+	if b == 0 { a } else { gcd(b, a % b) }
+}
@@ -0,0 +1,645 @@
+// core
+use core::str;
+// alloc
+#[cfg(target_has_atomic = "ptr")] use alloc::sync::Arc;
+use alloc::{borrow::Cow, boxed::Box, rc::Rc};
+// self
+use crate::{FromStrRadix, prelude::*};
+
+#[derive(Clone, Copy)]
+enum Radix {
+	Bin,
+	Oct,
+}
+impl Radix {
+	fn bits(self) -> u32 {
+		match self {
+			Self::Bin => 1,
+			Self::Oct => 3,
+		}
+	}
+
+	fn prefix(self) -> &'static str {
+		match self {
+			Self::Bin => "0b",
+			Self::Oct => "0o",
+		}
+	}
+
+	// The digits between the `_`s of the grouped form.
+	fn group(self) -> usize {
+		match self {
+			Self::Bin => 4,
+			Self::Oct => 3,
+		}
+	}
+
+	// The digits of a byte.
+	fn byte_width(self) -> usize {
+		match self {
+			Self::Bin => 8,
+			Self::Oct => 3,
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Form {
+	Minimal,
+	Prefixed,
+	Fixed,
+	Grouped,
+}
+
+/// Binify `Self`.
+///
+/// The unsigned integers are minimal unless the fixed-width form is used, the bytes always take 8
+/// digits each.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Binify;
+///
+/// // Unsigned.
+/// assert_eq!(5_u8.binify(), "101");
+/// assert_eq!(5_u16.binify_prefixed(), "0b101");
+/// assert_eq!(5_u32.binify_fixed(), "00000000000000000000000000000101");
+/// assert_eq!(0xa1_u8.binify_grouped(), "0b1010_0001");
+/// // `[u8; N]`.
+/// assert_eq!(b"Lo".binify(), "0100110001101111");
+/// // `&[u8]`.
+/// assert_eq!(b"Lo".as_slice().binify_prefixed(), "0b0100110001101111");
+/// // `Vec<u8>`.
+/// assert_eq!(b"Lo".to_vec().binify_grouped(), "0b0100_1100_0110_1111");
+/// ```
+pub trait Binify {
+	/// Binify `Self`.
+	fn binify(&self) -> String;
+
+	/// Binify `Self` with `0b` prefix.
+	fn binify_prefixed(&self) -> String;
+
+	/// Binify `Self` in its full width.
+	fn binify_fixed(&self) -> String;
+
+	/// Binify `Self` in its full width with `0b` prefix, grouping every 4 digits with `_`.
+	fn binify_grouped(&self) -> String;
+}
+
+/// Octify `Self`.
+///
+/// The unsigned integers are minimal unless the fixed-width form is used, the bytes always take 3
+/// digits each, from `000` to `377`.
+///
+/// # Examples
+/// ```
+/// use array_bytes::Octify;
+///
+/// // Unsigned.
+/// assert_eq!(0o755_u16.octify(), "755");
+/// assert_eq!(0o755_u16.octify_prefixed(), "0o755");
+/// assert_eq!(0o755_u16.octify_fixed(), "000755");
+/// assert_eq!(0o1777_u32.octify_grouped(), "0o00_000_001_777");
+/// // `[u8; N]`.
+/// assert_eq!(b"Lo".octify(), "114157");
+/// // `&[u8]`.
+/// assert_eq!(b"Lo".as_slice().octify_prefixed(), "0o114157");
+/// // `Vec<u8>`.
+/// assert_eq!(b"Lo".to_vec().octify_grouped(), "0o114_157");
+/// ```
+pub trait Octify {
+	/// Octify `Self`.
+	fn octify(&self) -> String;
+
+	/// Octify `Self` with `0o` prefix.
+	fn octify_prefixed(&self) -> String;
+
+	/// Octify `Self` in its full width.
+	fn octify_fixed(&self) -> String;
+
+	/// Octify `Self` in its full width with `0o` prefix, grouping every 3 digits with `_`.
+	fn octify_grouped(&self) -> String;
+}
+macro_rules! impl_radixify_for_unsigned {
+	($($t:ty,)+) => {
+		$(
+			impl Binify for $t {
+				fn binify(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Bin, Form::Minimal)
+				}
+
+				fn binify_prefixed(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Bin, Form::Prefixed)
+				}
+
+				fn binify_fixed(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Bin, Form::Fixed)
+				}
+
+				fn binify_grouped(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Bin, Form::Grouped)
+				}
+			}
+			impl Octify for $t {
+				fn octify(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Oct, Form::Minimal)
+				}
+
+				fn octify_prefixed(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Oct, Form::Prefixed)
+				}
+
+				fn octify_fixed(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Oct, Form::Fixed)
+				}
+
+				fn octify_grouped(&self) -> String {
+					radixify_unsigned(*self as _, <$t>::BITS, Radix::Oct, Form::Grouped)
+				}
+			}
+		)+
+	};
+}
+impl_radixify_for_unsigned! {
+	usize,
+	u8,
+	u16,
+	u32,
+	u64,
+	u128,
+}
+macro_rules! radixify_bytes_fns {
+	() => {
+		fn binify(&self) -> String {
+			radixify_bytes(self, Radix::Bin, Form::Minimal)
+		}
+
+		fn binify_prefixed(&self) -> String {
+			radixify_bytes(self, Radix::Bin, Form::Prefixed)
+		}
+
+		fn binify_fixed(&self) -> String {
+			radixify_bytes(self, Radix::Bin, Form::Fixed)
+		}
+
+		fn binify_grouped(&self) -> String {
+			radixify_bytes(self, Radix::Bin, Form::Grouped)
+		}
+	};
+	(oct) => {
+		fn octify(&self) -> String {
+			radixify_bytes(self, Radix::Oct, Form::Minimal)
+		}
+
+		fn octify_prefixed(&self) -> String {
+			radixify_bytes(self, Radix::Oct, Form::Prefixed)
+		}
+
+		fn octify_fixed(&self) -> String {
+			radixify_bytes(self, Radix::Oct, Form::Fixed)
+		}
+
+		fn octify_grouped(&self) -> String {
+			radixify_bytes(self, Radix::Oct, Form::Grouped)
+		}
+	};
+}
+impl<const N: usize> Binify for [u8; N] {
+	radixify_bytes_fns! {}
+}
+impl Binify for [u8] {
+	radixify_bytes_fns! {}
+}
+impl Binify for Vec<u8> {
+	radixify_bytes_fns! {}
+}
+impl<const N: usize> Octify for [u8; N] {
+	radixify_bytes_fns! { oct }
+}
+impl Octify for [u8] {
+	radixify_bytes_fns! { oct }
+}
+impl Octify for Vec<u8> {
+	radixify_bytes_fns! { oct }
+}
+#[test]
+fn binify_should_work() {
+	// Unsigned.
+	assert_eq!(0_u8.binify(), "0");
+	assert_eq!(0_u8.binify_prefixed(), "0b0");
+	assert_eq!(0_u8.binify_fixed(), "00000000");
+	assert_eq!(0_u8.binify_grouped(), "0b0000_0000");
+	assert_eq!(0xa1_u8.binify(), "10100001");
+	assert_eq!(0x1_0001_u32.binify_prefixed(), "0b10000000000000001");
+	assert_eq!(0x8001_u16.binify_grouped(), "0b1000_0000_0000_0001");
+	assert_eq!(u64::MAX.binify().len(), 64);
+	assert_eq!(u128::MAX.binify_fixed(), "1".repeat(128));
+	assert_eq!(1_usize.binify_fixed().len(), usize::BITS as usize);
+	// Bytes.
+	assert_eq!([].binify(), "");
+	assert_eq!([].binify_prefixed(), "0b");
+	assert_eq!([].binify_grouped(), "0b");
+	assert_eq!([0x00, 0x01].binify(), "0000000000000001");
+	assert_eq!([0x00, 0x01].as_slice().binify_fixed(), "0000000000000001");
+	assert_eq!([0xa1].to_vec().binify_grouped(), "0b1010_0001");
+}
+#[test]
+fn octify_should_work() {
+	// Unsigned.
+	assert_eq!(0_u8.octify(), "0");
+	assert_eq!(0_u8.octify_prefixed(), "0o0");
+	assert_eq!(0_u8.octify_fixed(), "000");
+	assert_eq!(u8::MAX.octify_grouped(), "0o377");
+	assert_eq!(u16::MAX.octify_grouped(), "0o177_777");
+	assert_eq!(u32::MAX.octify(), "37777777777");
+	assert_eq!(u64::MAX.octify_prefixed(), "0o1777777777777777777777");
+	assert_eq!(u128::MAX.octify_fixed().len(), 43);
+	assert_eq!(0o644_usize.octify(), "644");
+	// Bytes.
+	assert_eq!([].octify(), "");
+	assert_eq!([0x00, 0xff].octify(), "000377");
+	assert_eq!([0x00, 0xff].as_slice().octify_fixed(), "000377");
+	assert_eq!([0x00, 0x08, 0xff].to_vec().octify_grouped(), "0o000_010_377");
+}
+
+/// Debinify the given binary to `Self`.
+///
+/// The `0b` prefix is optional, and `_` is only allowed between the digits. The invalid characters
+/// are reported with [`Error::InvalidCharacter`] or [`Error::InvalidByte`] as [`Dehexify`] does.
+/// The unsigned integers are parsed with `from_str_radix`, the bytes must take 8 digits each, or
+/// the incomplete one is reported with [`Error::InvalidByteDigits`].
+///
+/// [`Dehexify`]: crate::Dehexify
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Debinify, Error, LengthUnit};
+///
+/// // Unsigned.
+/// assert_eq!(u8::debinify("0b1010_0001"), Ok(0xa1));
+/// assert_eq!(u32::debinify("101"), Ok(5));
+/// // `[u8; N]`.
+/// assert_eq!(<[u8; 2]>::debinify("0b0100_1100_0110_1111"), Ok(*b"Lo"));
+/// assert_eq!(
+/// 	<[u8; 1]>::debinify("0b0100_1100_0110_1111"),
+/// 	Err(Error::MismatchedLength { expect: 1, actual: 2, unit: LengthUnit::Byte })
+/// );
+/// // `Vec<u8>`.
+/// assert_eq!(<Vec<u8>>::debinify("0100110001101111"), Ok(b"Lo".to_vec()));
+/// // `Box<[u8]>`, and likewise `SmallVec<[u8; N]>`, `Rc<[u8]>`, `Arc<[u8]>` and `Cow<[u8]>`.
+/// assert_eq!(<Box<[u8]>>::debinify("0b0100_1100"), Ok(b"L".to_vec().into_boxed_slice()));
+/// assert_eq!(<Vec<u8>>::debinify("0b1010_001"), Err(Error::InvalidByteDigits { index: 2 }));
+/// assert_eq!(
+/// 	<Vec<u8>>::debinify("0b1010__0001"),
+/// 	Err(Error::InvalidCharacter { character: '_', index: 7, char_index: 7 })
+/// );
+/// ```
+pub trait Debinify
+where
+	Self: Sized,
+{
+	/// Debinify `Self` from binary.
+	fn debinify<B>(bin: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+}
+
+/// Deoctify the given octal to `Self`.
+///
+/// The `0o` prefix is optional, and `_` is only allowed between the digits. The invalid characters
+/// are reported with [`Error::InvalidCharacter`] or [`Error::InvalidByte`] as [`Dehexify`] does.
+/// The unsigned integers are parsed with `from_str_radix`, the bytes must take 3 digits each up to
+/// `377`, or the incomplete or overflowing one is reported with [`Error::InvalidByteDigits`].
+///
+/// [`Dehexify`]: crate::Dehexify
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Deoctify, Error};
+///
+/// // Unsigned.
+/// assert_eq!(u16::deoctify("0o755"), Ok(0o755));
+/// assert_eq!(u32::deoctify("0o00_000_001_777"), Ok(0o1777));
+/// // `[u8; N]`.
+/// assert_eq!(<[u8; 2]>::deoctify("0o114_157"), Ok(*b"Lo"));
+/// // `Vec<u8>`.
+/// assert_eq!(<Vec<u8>>::deoctify("114157"), Ok(b"Lo".to_vec()));
+/// // `Box<[u8]>`, and likewise `SmallVec<[u8; N]>`, `Rc<[u8]>`, `Arc<[u8]>` and `Cow<[u8]>`.
+/// assert_eq!(<Box<[u8]>>::deoctify("0o114"), Ok(b"L".to_vec().into_boxed_slice()));
+/// assert_eq!(<Vec<u8>>::deoctify("114400"), Err(Error::InvalidByteDigits { index: 3 }));
+/// assert_eq!(
+/// 	<Vec<u8>>::deoctify("0o118"),
+/// 	Err(Error::InvalidCharacter { character: '8', index: 4, char_index: 4 })
+/// );
+/// ```
+pub trait Deoctify
+where
+	Self: Sized,
+{
+	/// Deoctify `Self` from octal.
+	fn deoctify<O>(oct: O) -> Result<Self>
+	where
+		O: AsRef<[u8]>;
+}
+macro_rules! impl_deradixify_for_unsigned {
+	($($t:ty,)+) => {
+		$(
+			impl Debinify for $t {
+				fn debinify<B>(bin: B) -> Result<Self>
+				where
+					B: AsRef<[u8]>,
+				{
+					deradixify_unsigned(bin.as_ref(), Radix::Bin)
+				}
+			}
+			impl Deoctify for $t {
+				fn deoctify<O>(oct: O) -> Result<Self>
+				where
+					O: AsRef<[u8]>,
+				{
+					deradixify_unsigned(oct.as_ref(), Radix::Oct)
+				}
+			}
+		)+
+	};
+}
+impl_deradixify_for_unsigned! {
+	usize,
+	u8,
+	u16,
+	u32,
+	u64,
+	u128,
+}
+macro_rules! deradixify_fns {
+	($method:ident, $radix:expr, $f:ident $(, $map:expr)?) => {
+		fn $method<R>(radix: R) -> Result<Self>
+		where
+			R: AsRef<[u8]>,
+		{
+			$f(radix.as_ref(), $radix)$(.map($map))?
+		}
+	};
+}
+impl<const N: usize> Debinify for [u8; N] {
+	deradixify_fns! { debinify, Radix::Bin, deradixify_array }
+}
+impl<const N: usize> Debinify for SmallVec<[u8; N]> {
+	deradixify_fns! { debinify, Radix::Bin, deradixify_bytes, SmallVec::from_vec }
+}
+impl Debinify for Vec<u8> {
+	deradixify_fns! { debinify, Radix::Bin, deradixify_bytes }
+}
+impl Debinify for Box<[u8]> {
+	deradixify_fns! { debinify, Radix::Bin, deradixify_bytes, Into::into }
+}
+impl Debinify for Rc<[u8]> {
+	deradixify_fns! { debinify, Radix::Bin, deradixify_bytes, Into::into }
+}
+#[cfg(target_has_atomic = "ptr")]
+impl Debinify for Arc<[u8]> {
+	deradixify_fns! { debinify, Radix::Bin, deradixify_bytes, Into::into }
+}
+impl Debinify for Cow<'static, [u8]> {
+	deradixify_fns! { debinify, Radix::Bin, deradixify_bytes, Cow::Owned }
+}
+impl<const N: usize> Deoctify for [u8; N] {
+	deradixify_fns! { deoctify, Radix::Oct, deradixify_array }
+}
+impl<const N: usize> Deoctify for SmallVec<[u8; N]> {
+	deradixify_fns! { deoctify, Radix::Oct, deradixify_bytes, SmallVec::from_vec }
+}
+impl Deoctify for Vec<u8> {
+	deradixify_fns! { deoctify, Radix::Oct, deradixify_bytes }
+}
+impl Deoctify for Box<[u8]> {
+	deradixify_fns! { deoctify, Radix::Oct, deradixify_bytes, Into::into }
+}
+impl Deoctify for Rc<[u8]> {
+	deradixify_fns! { deoctify, Radix::Oct, deradixify_bytes, Into::into }
+}
+#[cfg(target_has_atomic = "ptr")]
+impl Deoctify for Arc<[u8]> {
+	deradixify_fns! { deoctify, Radix::Oct, deradixify_bytes, Into::into }
+}
+impl Deoctify for Cow<'static, [u8]> {
+	deradixify_fns! { deoctify, Radix::Oct, deradixify_bytes, Cow::Owned }
+}
+#[test]
+fn debinify_should_work() {
+	// Unsigned.
+	assert_eq!(u8::debinify("0"), Ok(0));
+	assert_eq!(u8::debinify("0b11111111"), Ok(u8::MAX));
+	assert_eq!(u16::debinify("0b1000_0000_0000_0001"), Ok(0x8001));
+	assert_eq!(u128::debinify("1".repeat(128)), Ok(u128::MAX));
+	assert_eq!(usize::debinify("0b0000_0101"), Ok(5));
+	assert_eq!(
+		u8::debinify("1_0000_0000"),
+		Err(Error::ParseIntError(u8::from_str_radix("100000000", 2).unwrap_err()))
+	);
+	assert_eq!(u8::debinify(""), Err(Error::ParseIntError(u8::from_str_radix("", 2).unwrap_err())));
+	assert_eq!(
+		u8::debinify("0b"),
+		Err(Error::ParseIntError(u8::from_str_radix("", 2).unwrap_err()))
+	);
+	assert_eq!(
+		u8::debinify("+1"),
+		Err(Error::InvalidCharacter { character: '+', index: 0, char_index: 0 })
+	);
+	assert_eq!(
+		u8::debinify("0b102"),
+		Err(Error::InvalidCharacter { character: '2', index: 4, char_index: 4 })
+	);
+	assert_eq!(
+		u8::debinify("0B1"),
+		Err(Error::InvalidCharacter { character: 'B', index: 1, char_index: 1 })
+	);
+	assert_eq!(
+		u8::debinify("0b_1"),
+		Err(Error::InvalidCharacter { character: '_', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		u8::debinify("0b1_"),
+		Err(Error::InvalidCharacter { character: '_', index: 3, char_index: 3 })
+	);
+	assert_eq!(u8::debinify(b"0b\xff"), Err(Error::InvalidByte { byte: 0xff, index: 2 }));
+	// Bytes.
+	assert_eq!(<Vec<u8>>::debinify(""), Ok(Vec::new()));
+	assert_eq!(<Vec<u8>>::debinify("0b"), Ok(Vec::new()));
+	assert_eq!(<[u8; 2]>::debinify("0000000011111111"), Ok([0x00, 0xff]));
+	assert_eq!(<Vec<u8>>::debinify("0b1"), Err(Error::InvalidByteDigits { index: 2 }));
+	assert_eq!(
+		<Vec<u8>>::debinify("0b0000_0000_1111_1111_0"),
+		Err(Error::InvalidByteDigits { index: 22 })
+	);
+	assert_eq!(
+		<Vec<u8>>::debinify("0000000我"),
+		Err(Error::InvalidCharacter { character: '我', index: 7, char_index: 7 })
+	);
+	assert_eq!(
+		<[u8; 2]>::debinify("00000000"),
+		Err(Error::MismatchedLength { expect: 2, actual: 1, unit: LengthUnit::Byte })
+	);
+	// Containers.
+	assert_eq!(
+		<SmallVec<[u8; 2]>>::debinify("0b0100_1100_0110_1111"),
+		Ok(SmallVec::from_slice(b"Lo"))
+	);
+	assert_eq!(<Box<[u8]>>::debinify("0b0100_1100"), Ok(b"L".to_vec().into_boxed_slice()));
+	assert_eq!(<Rc<[u8]>>::debinify("0b0100_1100"), Ok(Rc::from(b"L".as_slice())));
+	#[cfg(target_has_atomic = "ptr")]
+	assert_eq!(<Arc<[u8]>>::debinify("0b0100_1100"), Ok(Arc::from(b"L".as_slice())));
+	assert_eq!(<Cow<'static, [u8]>>::debinify("0b0100_1100"), Ok(Cow::Owned(b"L".to_vec())));
+}
+#[test]
+fn deoctify_should_work() {
+	// Unsigned.
+	assert_eq!(u8::deoctify("0o377"), Ok(u8::MAX));
+	assert_eq!(u16::deoctify("177_777"), Ok(u16::MAX));
+	assert_eq!(u64::deoctify("0o1777777777777777777777"), Ok(u64::MAX));
+	assert_eq!(u128::deoctify(u128::MAX.octify_grouped()), Ok(u128::MAX));
+	assert_eq!(usize::deoctify("0o644"), Ok(0o644));
+	assert_eq!(
+		u8::deoctify("0o400"),
+		Err(Error::ParseIntError(u8::from_str_radix("400", 8).unwrap_err()))
+	);
+	assert_eq!(
+		u8::deoctify("0o9"),
+		Err(Error::InvalidCharacter { character: '9', index: 2, char_index: 2 })
+	);
+	assert_eq!(
+		u8::deoctify("0x1"),
+		Err(Error::InvalidCharacter { character: 'x', index: 1, char_index: 1 })
+	);
+	// Bytes.
+	assert_eq!(<Vec<u8>>::deoctify("0o"), Ok(Vec::new()));
+	assert_eq!(<[u8; 3]>::deoctify("0o000_010_377"), Ok([0x00, 0x08, 0xff]));
+	assert_eq!(<Vec<u8>>::deoctify("0o37"), Err(Error::InvalidByteDigits { index: 2 }));
+	assert_eq!(<Vec<u8>>::deoctify("0o377_4"), Err(Error::InvalidByteDigits { index: 6 }));
+	assert_eq!(<Vec<u8>>::deoctify("0o000_777"), Err(Error::InvalidByteDigits { index: 6 }));
+	assert_eq!(
+		<Vec<u8>>::deoctify("0o000__377"),
+		Err(Error::InvalidCharacter { character: '_', index: 6, char_index: 6 })
+	);
+	assert_eq!(
+		<[u8; 1]>::deoctify("000377"),
+		Err(Error::MismatchedLength { expect: 1, actual: 2, unit: LengthUnit::Byte })
+	);
+	// Containers.
+	assert_eq!(<SmallVec<[u8; 2]>>::deoctify("0o114_157"), Ok(SmallVec::from_slice(b"Lo")));
+	assert_eq!(<Box<[u8]>>::deoctify("0o114"), Ok(b"L".to_vec().into_boxed_slice()));
+	assert_eq!(<Rc<[u8]>>::deoctify("0o114"), Ok(Rc::from(b"L".as_slice())));
+	#[cfg(target_has_atomic = "ptr")]
+	assert_eq!(<Arc<[u8]>>::deoctify("0o114"), Ok(Arc::from(b"L".as_slice())));
+	assert_eq!(<Cow<'static, [u8]>>::deoctify("0o114"), Ok(Cow::Owned(b"L".to_vec())));
+}
+
+fn radixify_unsigned(value: u128, bits: u32, radix: Radix, form: Form) -> String {
+	let width = if matches!(form, Form::Fixed | Form::Grouped) {
+		bits.div_ceil(radix.bits())
+	} else {
+		(u128::BITS - value.leading_zeros()).div_ceil(radix.bits()).max(1)
+	};
+	let mask = (1 << radix.bits()) - 1;
+	let digits = (0..width).rev().map(|i| b'0' + ((value >> (i * radix.bits())) & mask) as u8);
+
+	format(digits, width as _, radix, form)
+}
+
+fn radixify_bytes(bytes: &[u8], radix: Radix, form: Form) -> String {
+	let width = radix.byte_width();
+	let mask = (1 << radix.bits()) - 1;
+	let digits = bytes.iter().flat_map(|byte| {
+		(0..width).rev().map(move |i| b'0' + ((byte >> (i as u32 * radix.bits())) & mask))
+	});
+
+	format(digits, bytes.len() * width, radix, form)
+}
+
+// Collect the digits, with the prefix and the `_`s of the form.
+fn format<I>(digits: I, len: usize, radix: Radix, form: Form) -> String
+where
+	I: Iterator<Item = u8>,
+{
+	let mut s = String::with_capacity(2 + len + len / radix.group());
+
+	if matches!(form, Form::Prefixed | Form::Grouped) {
+		s.push_str(radix.prefix());
+	}
+
+	for (i, digit) in digits.enumerate() {
+		// Group from the right.
+		if form == Form::Grouped && i != 0 && (len - i).is_multiple_of(radix.group()) {
+			s.push('_');
+		}
+
+		s.push(digit as _);
+	}
+
+	s
+}
+
+// Check the digits and the `_`s, then return the digits with their indexes.
+fn digits(input: &[u8], radix: Radix) -> Result<Vec<(u8, usize)>> {
+	let offset = if input.starts_with(radix.prefix().as_bytes()) { 2 } else { 0 };
+	let max = b'0' + (1 << radix.bits()) - 1;
+	let mut digits = Vec::with_capacity(input.len() - offset);
+
+	for (i, &c) in input.iter().enumerate().skip(offset) {
+		let valid = match c {
+			b'_' => i != offset && i != input.len() - 1 && input[i - 1] != b'_',
+			c => (b'0'..=max).contains(&c),
+		};
+
+		if !valid {
+			// The characters before `i` are all checked, they are ASCII; qed.
+			Err(Error::invalid_character(&input[i..], i, i))?;
+		}
+		if c != b'_' {
+			digits.push((c - b'0', i));
+		}
+	}
+
+	Ok(digits)
+}
+
+fn deradixify_unsigned<T>(input: &[u8], radix: Radix) -> Result<T>
+where
+	T: FromStrRadix,
+{
+	let digits =
+		digits(input, radix)?.into_iter().map(|(d, _)| b'0' + d).collect::<SmallVec<[u8; 128]>>();
+	// All the digits are checked, they are ASCII; qed.
+	let digits = unsafe { str::from_utf8_unchecked(&digits) };
+
+	T::from_str_radix(digits, 1 << radix.bits()).map_err(Error::ParseIntError)
+}
+
+fn deradixify_bytes(input: &[u8], radix: Radix) -> Result<Vec<u8>> {
+	let digits = digits(input, radix)?;
+	let width = radix.byte_width();
+
+	if !digits.len().is_multiple_of(width) {
+		Err(Error::InvalidByteDigits { index: digits[digits.len() - digits.len() % width].1 })?;
+	}
+
+	digits
+		.chunks_exact(width)
+		.map(|chunk| {
+			let byte = chunk.iter().fold(0_u32, |n, (d, _)| n << radix.bits() | *d as u32);
+
+			// Only 3 octal digits can overflow.
+			u8::try_from(byte).map_err(|_| Error::InvalidByteDigits { index: chunk[0].1 })
+		})
+		.collect()
+}
+
+fn deradixify_array<const N: usize>(input: &[u8], radix: Radix) -> Result<[u8; N]> {
+	let bytes = deradixify_bytes(input, radix)?;
+
+	bytes.as_slice().try_into().map_err(|_| Error::MismatchedLength {
+		expect: N,
+		actual: bytes.len(),
+		unit: LengthUnit::Byte,
+	})
+}
@@ -0,0 +1,359 @@
+// core
+use core::{
+	fmt::{Display, Formatter, Result as FmtResult},
+	num::IntErrorKind,
+};
+// alloc
+#[cfg(any(test, feature = "miette"))] use alloc::boxed::Box;
+// self
+use crate::prelude::*;
+
+const SNIPPET_WIDTH: usize = 64;
+
+/// Render an [`Error`] against the original input.
+///
+/// The rendered report contains the error message, a snippet of the input with a caret under the
+/// offending character and a hint if there is one. The hints of the invalid characters depend on
+/// the alphabet, they are only given once the [`Codec`] is set.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Codec, Dehexify};
+///
+/// let hex = "0X4c6f7665";
+/// let e = <Vec<u8>>::dehexify(hex).unwrap_err();
+///
+/// assert_eq!(
+/// 	e.report(hex).codec(Codec::Hex).to_string(),
+/// 	"\
+/// error: invalid character(X) at index(1), char index(1)
+///   | 0X4c6f7665
+///   |  ^
+///   = hint: did you mean `0x`?"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Report<'a> {
+	error: &'a Error,
+	input: &'a str,
+	codec: Option<Codec>,
+}
+impl Report<'_> {
+	/// Set the codec which produced the error, for the codec-specific hints.
+	pub fn codec(mut self, codec: Codec) -> Self {
+		self.codec = Some(codec);
+
+		self
+	}
+
+	fn column(&self) -> Option<usize> {
+		match self.error {
+			Error::InvalidLength { .. } => Some(self.input.chars().count()),
+			e => self.input.get(..e.span()?.start).map(|s| s.chars().count()),
+		}
+	}
+}
+impl Display for Report<'_> {
+	fn fmt(&self, f: &mut Formatter) -> FmtResult {
+		write!(f, "error: {}", self.error)?;
+
+		if let Some(column) = self.column() {
+			let len = self.input.chars().count();
+			let start =
+				column.saturating_sub(SNIPPET_WIDTH / 2).min(len.saturating_sub(SNIPPET_WIDTH));
+			let end = (start + SNIPPET_WIDTH).min(len);
+			let (head, tail) =
+				(if start == 0 { "" } else { "..." }, if end == len { "" } else { "..." });
+
+			f.write_str("\n  | ")?;
+			f.write_str(head)?;
+
+			for c in self.input.chars().skip(start).take(end - start) {
+				write!(f, "{c}")?;
+			}
+
+			f.write_str(tail)?;
+			write!(f, "\n  | {:>1$}", '^', head.len() + column - start + 1)?;
+		}
+		let hint = match self.codec {
+			Some(codec) => self.error.hint_for(codec),
+			None => self.error.hint(),
+		};
+
+		if let Some(hint) = hint {
+			write!(f, "\n  = hint: {hint}")?;
+		}
+
+		Ok(())
+	}
+}
+#[test]
+fn report_should_work() {
+	use alloc::string::ToString;
+
+	let hex = "4c6f7665g";
+	assert_eq!(
+		Error::InvalidLength { expect: 10, actual: 9, unit: LengthUnit::Nibble }
+			.report(hex)
+			.to_string(),
+		"\
+error: invalid length, expected 10 nibbles, got 9
+  | 4c6f7665g
+  |          ^
+  = hint: odd length: missing one nibble, try padding with a leading `0`"
+	);
+	assert_eq!(
+		Error::InvalidCharacter { character: 'g', index: 8, char_index: 8 }
+			.report(hex)
+			.codec(Codec::Hex)
+			.to_string(),
+		"\
+error: invalid character(g) at index(8), char index(8)
+  | 4c6f7665g
+  |         ^
+  = hint: hex digits are `0-9`, `a-f` and `A-F`"
+	);
+
+	let hex = "0x我爱你";
+	assert_eq!(
+		Error::InvalidCharacter { character: '爱', index: 5, char_index: 3 }
+			.report(hex)
+			.codec(Codec::Hex)
+			.to_string(),
+		"\
+error: invalid character(爱) at index(5), char index(3)
+  | 0x我爱你
+  |    ^
+  = hint: hex digits are `0-9`, `a-f` and `A-F`"
+	);
+
+	let hex = "0".repeat(100) + "z" + &"0".repeat(99);
+	assert_eq!(
+		Error::InvalidCharacter { character: 'z', index: 100, char_index: 100 }
+			.report(&hex)
+			.codec(Codec::Hex)
+			.to_string(),
+		alloc::format!(
+			"\
+error: invalid character(z) at index(100), char index(100)
+  | ...{}z{}...
+  | {:>36}
+  = hint: hex digits are `0-9`, `a-f` and `A-F`",
+			"0".repeat(32),
+			"0".repeat(31),
+			'^'
+		)
+	);
+
+	assert_eq!(
+		Error::MismatchedLength { expect: 2, actual: 1, unit: LengthUnit::Byte }
+			.report("00")
+			.to_string(),
+		"error: mismatched length, expected 2 bytes, got 1"
+	);
+
+	// Not hex, the hint is about the Base58 alphabet.
+	let base58 = "2xKVt0";
+	let e = <Vec<u8> as crate::Debase58ify>::debase58ify(base58).unwrap_err();
+
+	assert_eq!(
+		e.report(base58).codec(Codec::Base58).to_string(),
+		"\
+error: invalid character(0) at index(5), char index(5)
+  | 2xKVt0
+  |      ^
+  = hint: Base58 excludes `0`, `O`, `I` and `l`"
+	);
+	assert_eq!(
+		e.report(base58).to_string(),
+		"\
+error: invalid character(0) at index(5), char index(5)
+  | 2xKVt0
+  |      ^"
+	);
+}
+
+/// The codec which produced an [`Error`].
+///
+/// The same variant, such as [`Error::InvalidCharacter`], is returned by all the codecs, the codec
+/// tells which alphabet the hint is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Codec {
+	/// Hex.
+	Hex,
+	/// Base32 of any variant.
+	Base32,
+	/// Base58.
+	Base58,
+	/// Base64 of any variant.
+	Base64,
+	/// Bech32 and Bech32m.
+	Bech32,
+	/// Binary.
+	Binary,
+	/// Octal.
+	Octal,
+	/// Decimal.
+	Decimal,
+}
+
+impl Error {
+	/// Create a [`Report`] to render `self` against the original input.
+	pub fn report<'a>(&'a self, input: &'a str) -> Report<'a> {
+		Report { error: self, input, codec: None }
+	}
+
+	/// Return a hint on how to fix the input, if there is one.
+	///
+	/// Only the hints which hold for any codec are returned, see [`Error::hint_for`] for the hints
+	/// about the alphabet.
+	pub fn hint(&self) -> Option<&'static str> {
+		match self {
+			Self::ParseIntError(e) => match e.kind() {
+				IntErrorKind::Empty => Some("the input must not be empty"),
+				IntErrorKind::PosOverflow => Some("the value is too large for the target type"),
+				_ => None,
+			},
+			Self::InvalidLength { unit: LengthUnit::Nibble, .. } =>
+				Some("odd length: missing one nibble, try padding with a leading `0`"),
+			Self::InvalidCharacter { character, .. } if character.is_whitespace() =>
+				Some("whitespace is not allowed here"),
+			Self::InvalidByte { .. } => Some("the input is not valid UTF-8 here"),
+			Self::InvalidElement { source, .. } => source.hint(),
+			Self::InvalidRecord { .. } =>
+				Some("check the start code, the byte count and the record type"),
+			Self::InvalidChecksum { .. } => Some("the record is corrupted or was edited by hand"),
+			Self::MismatchedChecksum { .. } => Some("the input is corrupted or mistyped"),
+			Self::InvalidPadding { .. } =>
+				Some("the padding must complete the last group and only appear at the end"),
+			Self::InvalidTrailingBits { .. } =>
+				Some("the encoding is not canonical, was the input truncated or edited?"),
+			Self::InvalidSeparator { .. } =>
+				Some("the separator `1` must be followed by at least 6 characters"),
+			Self::InvalidHrpLength { .. } =>
+				Some("the human-readable part must be 1 to 83 characters"),
+			Self::InvalidByteDigits { .. } =>
+				Some("each byte takes 8 binary digits, or 3 octal digits up to `377`"),
+			Self::InvalidEscape { .. } => Some(
+				"the escapes are `\\\\`, `\\'`, `\\\"`, `\\0`, `\\t`, `\\n`, `\\r` and `\\xNN`",
+			),
+			Self::UnknownMultibase { .. } => Some(
+				"the supported codes are `f`, `F`, `b`, `B`, `c`, `C`, `v`, `V`, `t`, `T`, `z`, `Z`, `m`, `M`, `u` and `U`",
+			),
+			_ => None,
+		}
+	}
+
+	/// Return a hint on how to fix the input decoded by the given codec, if there is one.
+	///
+	/// It falls back to [`Error::hint`] if there is nothing specific to the codec.
+	pub fn hint_for(&self, codec: Codec) -> Option<&'static str> {
+		let character = match self {
+			Self::InvalidElement { source, .. } => return source.hint_for(codec),
+			Self::ParseIntError(e) if codec == Codec::Hex && *e.kind() == IntErrorKind::Empty =>
+				return Some("the hex must not be empty"),
+			Self::InvalidCharacter { character, .. } if !character.is_whitespace() => *character,
+			_ => return self.hint(),
+		};
+		let hint = match codec {
+			Codec::Hex => match (character, self) {
+				('X', Self::InvalidCharacter { char_index: 1, .. }) => "did you mean `0x`?",
+				('x' | 'X', _) => "the `0x` prefix is only allowed at the beginning",
+				(c, _) if c.is_ascii_hexdigit() =>
+					"the letter case of this digit is not accepted here",
+				_ => "hex digits are `0-9`, `a-f` and `A-F`",
+			},
+			Codec::Base32 => "the character is not in the Base32 alphabet of this variant",
+			Codec::Base58 => match character {
+				'0' | 'O' | 'I' | 'l' => "Base58 excludes `0`, `O`, `I` and `l`",
+				_ => "Base58 digits are `1-9`, `A-Z` and `a-z`, except `O`, `I` and `l`",
+			},
+			Codec::Base64 =>
+				"Base64 digits are `A-Z`, `a-z`, `0-9`, `+` and `/`, or `-` and `_` if URL-safe",
+			Codec::Bech32 =>
+				"Bech32 digits are `a-z` and `0-9` except `1`, `b`, `i` and `o`, in a single case",
+			Codec::Binary => "binary digits are `0` and `1`, `_` is only allowed between digits",
+			Codec::Octal => "octal digits are `0-7`, `_` is only allowed between digits",
+			Codec::Decimal =>
+				"decimal digits are `0-9`, the separators are only allowed between the thousands",
+		};
+
+		Some(hint)
+	}
+}
+#[test]
+fn hint_should_work() {
+	let invalid_character = |character, char_index| Error::InvalidCharacter {
+		character,
+		index: char_index,
+		char_index,
+	};
+
+	assert_eq!(invalid_character('X', 1).hint_for(Codec::Hex), Some("did you mean `0x`?"));
+	assert_eq!(
+		invalid_character('x', 4).hint_for(Codec::Hex),
+		Some("the `0x` prefix is only allowed at the beginning")
+	);
+	assert_eq!(
+		invalid_character('A', 4).hint_for(Codec::Hex),
+		Some("the letter case of this digit is not accepted here")
+	);
+	assert_eq!(
+		invalid_character('0', 4).hint_for(Codec::Base58),
+		Some("Base58 excludes `0`, `O`, `I` and `l`")
+	);
+	assert_eq!(
+		invalid_character('2', 4).hint_for(Codec::Binary),
+		Some("binary digits are `0` and `1`, `_` is only allowed between digits")
+	);
+	// The alphabet is unknown without the codec.
+	assert_eq!(invalid_character('0', 4).hint(), None);
+	assert_eq!(invalid_character(' ', 4).hint(), Some("whitespace is not allowed here"));
+	assert_eq!(
+		invalid_character(' ', 4).hint_for(Codec::Hex),
+		Some("whitespace is not allowed here")
+	);
+	assert_eq!(
+		Error::InvalidElement { element: 1, source: Box::new(invalid_character('g', 4)) }
+			.hint_for(Codec::Hex),
+		Some("hex digits are `0-9`, `a-f` and `A-F`")
+	);
+	assert_eq!(
+		<u8 as crate::Dehexify>::dehexify("").unwrap_err().hint_for(Codec::Hex),
+		Some("the hex must not be empty")
+	);
+	assert_eq!(
+		<u8 as crate::Debinify>::debinify("").unwrap_err().hint_for(Codec::Binary),
+		Some("the input must not be empty")
+	);
+	assert_eq!(
+		<u8 as crate::Dehexify>::dehexify("0x100").unwrap_err().hint(),
+		Some("the value is too large for the target type")
+	);
+	assert_eq!(Error::CapacityOverflow { capacity: 1, length: 2 }.hint(), None);
+}
+
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for Error {
+	fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+		self.hint().map(|hint| Box::new(hint) as _)
+	}
+
+	fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+		let span = self.span()?;
+
+		Some(Box::new(core::iter::once(miette::LabeledSpan::at(span, "here"))))
+	}
+}
+#[cfg(feature = "miette")]
+#[test]
+fn miette_should_work() {
+	use alloc::string::ToString;
+
+	use miette::Diagnostic;
+
+	let e = Error::InvalidCharacter { character: ' ', index: 8, char_index: 8 };
+
+	assert_eq!(e.help().unwrap().to_string(), "whitespace is not allowed here");
+	assert_eq!(e.labels().unwrap().collect::<Vec<_>>(), [miette::LabeledSpan::at(8..9, "here")]);
+}
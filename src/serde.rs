@@ -1,15 +1,33 @@
+//! The serde helpers, as free functions for `serialize_with`/`deserialize_with` and as modules for
+//! `with`.
+//!
+//! The free functions are re-exported at the crate root.
+
 // core
-use core::str;
+use core::{
+	fmt::{Formatter, Result as FmtResult},
+	marker::PhantomData,
+	str,
+};
 // alloc
 use alloc::format;
 // crates.io
 #[cfg(test)] use serde::Serialize;
-use serde::{Deserialize, Deserializer, Serializer, de::Error as _, ser::Error as _};
+use serde::{
+	Deserialize, Deserializer, Serializer,
+	de::{Error as DeError, SeqAccess, Visitor},
+	ser::Error as _,
+};
 // self
-use crate::{Dehexify, Hexify, prelude::*};
+use crate::{
+	Base64ify, Debase64ify, Deescapify, Dehexify, Dess58ify, Encoding, Escapify, Hexify, Ss58ify,
+	prelude::*,
+};
 
 /// Serialize bytes to string.
 ///
+/// The bytes must be valid UTF-8, see [`ser_escapify`] for arbitrary bytes.
+///
 /// # Examples
 /// ```
 /// use serde::Serialize;
@@ -159,6 +177,8 @@ where
 
 /// Deserialize string to bytes.
 ///
+/// The escapes are kept as is, see [`de_deescapify`] for the escaped strings.
+///
 /// # Examples
 /// ```
 /// use serde::Deserialize;
@@ -183,6 +203,84 @@ where
 	Ok(str.as_bytes().to_vec())
 }
 
+/// Serialize `T` to an escaped string, which keeps the non-UTF-8 bytes.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Ljf {
+/// 	#[serde(serialize_with = "array_bytes::ser_escapify")]
+/// 	_0: Vec<u8>,
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::to_string::<Ljf>(&Ljf { _0: b"Love\xffJane\n".to_vec() }).unwrap(),
+/// 	r#"{"_0":"Love\\xffJane\\n"}"#
+/// );
+/// ```
+pub fn ser_escapify<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: ?Sized + Escapify,
+{
+	serializer.serialize_str(&value.escapify())
+}
+
+/// Deserialize an escaped string to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Ljf {
+/// 	#[serde(deserialize_with = "array_bytes::de_deescapify")]
+/// 	_0: Vec<u8>,
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::from_str::<Ljf>(r#"{"_0":"Love\\xffJane\\n"}"#).unwrap(),
+/// 	Ljf { _0: b"Love\xffJane\n".to_vec() }
+/// );
+/// ```
+pub fn de_deescapify<'de, D, T>(escaped: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Deescapify,
+{
+	// Owned, since a JSON string with escapes can't be borrowed.
+	let escaped = String::deserialize(escaped)?;
+
+	T::deescapify(escaped).map_err(|e| D::Error::custom(format!("{e:?}")))
+}
+#[test]
+fn escape_serde_should_work() {
+	use alloc::string::ToString;
+
+	#[derive(Debug, PartialEq, Deserialize, Serialize)]
+	struct Ljf {
+		#[serde(deserialize_with = "de_deescapify", serialize_with = "ser_escapify")]
+		_0: Vec<u8>,
+		#[serde(deserialize_with = "de_deescapify", serialize_with = "ser_escapify")]
+		_1: [u8; 4],
+	}
+
+	let ljf = Ljf { _0: b"\xe6\x88\x91\xe6\x88\"\t".to_vec(), _1: [0, 0x7f, 0x80, 0xff] };
+	let json = serde_json::to_string(&ljf).unwrap();
+
+	assert_eq!(json, r#"{"_0":"我\\xe6\\x88\"\\t","_1":"\\x00\\x7f\\x80\\xff"}"#);
+	assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+	assert!(
+		serde_json::from_str::<Ljf>(r#"{"_0":"\\q","_1":"Love"}"#)
+			.unwrap_err()
+			.to_string()
+			.starts_with("InvalidEscape { index: 0 }")
+	);
+	assert!(serde_json::from_str::<Ljf>(r#"{"_0":"","_1":"Jane!"}"#).is_err());
+}
+
 /// Deserialize hex to `T`.
 ///
 /// # Examples
@@ -356,6 +454,695 @@ fn dehexify_array_then_deserialize_into_should_work() {
 	);
 }
 
+/// Serialize `T` to hex and deserialize hex to `T`, for `#[serde(with =
+/// "array_bytes::serde::hex")]`.
+///
+/// It's the same as [`ser_hexify`] with [`de_dehexify`].
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Ljf {
+/// 	#[serde(with = "array_bytes::serde::hex")]
+/// 	_0: u32,
+/// 	#[serde(with = "array_bytes::serde::hex")]
+/// 	_1: [u8; 4],
+/// }
+///
+/// let ljf = Ljf { _0: 5_201_314, _1: *b"Love" };
+/// let json = serde_json::to_string(&ljf).unwrap();
+///
+/// assert_eq!(json, r#"{"_0":"4f5da2","_1":"4c6f7665"}"#);
+/// assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+/// ```
+pub mod hex {
+	pub use super::{de_dehexify as deserialize, ser_hexify as serialize};
+}
+
+/// Serialize `T` to uppercase hex and deserialize hex to `T`, for `#[serde(with =
+/// "array_bytes::serde::hex_upper")]`.
+///
+/// It's the same as [`ser_hexify_upper`] with [`de_dehexify`].
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Ljf {
+/// 	#[serde(with = "array_bytes::serde::hex_upper")]
+/// 	_0: u32,
+/// 	#[serde(with = "array_bytes::serde::hex_upper")]
+/// 	_1: [u8; 4],
+/// }
+///
+/// let ljf = Ljf { _0: 5_201_314, _1: *b"Love" };
+/// let json = serde_json::to_string(&ljf).unwrap();
+///
+/// assert_eq!(json, r#"{"_0":"4F5DA2","_1":"4C6F7665"}"#);
+/// assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+/// ```
+pub mod hex_upper {
+	pub use super::{de_dehexify as deserialize, ser_hexify_upper as serialize};
+}
+
+/// Serialize `T` to hex with `0x` prefix and deserialize hex to `T`, for `#[serde(with =
+/// "array_bytes::serde::hex_prefixed")]`.
+///
+/// It's the same as [`ser_hexify_prefixed`] with [`de_dehexify`].
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Ljf {
+/// 	#[serde(with = "array_bytes::serde::hex_prefixed")]
+/// 	_0: u32,
+/// 	#[serde(with = "array_bytes::serde::hex_prefixed")]
+/// 	_1: [u8; 4],
+/// }
+///
+/// let ljf = Ljf { _0: 5_201_314, _1: *b"Love" };
+/// let json = serde_json::to_string(&ljf).unwrap();
+///
+/// assert_eq!(json, r#"{"_0":"0x4f5da2","_1":"0x4c6f7665"}"#);
+/// assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+/// ```
+pub mod hex_prefixed {
+	pub use super::{de_dehexify as deserialize, ser_hexify_prefixed as serialize};
+}
+
+/// Serialize `T` to uppercase hex with `0x` prefix and deserialize hex to `T`, for `#[serde(with =
+/// "array_bytes::serde::hex_prefixed_upper")]`.
+///
+/// It's the same as [`ser_hexify_prefixed_upper`] with [`de_dehexify`].
+///
+/// # Examples
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Ljf {
+/// 	#[serde(with = "array_bytes::serde::hex_prefixed_upper")]
+/// 	_0: u32,
+/// 	#[serde(with = "array_bytes::serde::hex_prefixed_upper")]
+/// 	_1: [u8; 4],
+/// }
+///
+/// let ljf = Ljf { _0: 5_201_314, _1: *b"Love" };
+/// let json = serde_json::to_string(&ljf).unwrap();
+///
+/// assert_eq!(json, r#"{"_0":"0x4F5DA2","_1":"0x4C6F7665"}"#);
+/// assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+/// ```
+pub mod hex_prefixed_upper {
+	pub use super::{de_dehexify as deserialize, ser_hexify_prefixed_upper as serialize};
+}
+
+#[test]
+fn hex_modules_should_work() {
+	#[derive(Debug, PartialEq, Deserialize, Serialize)]
+	struct Ljf {
+		#[serde(with = "hex")]
+		_0: u8,
+		#[serde(with = "hex_upper")]
+		_1: u16,
+		#[serde(with = "hex_prefixed")]
+		_2: [u8; 4],
+		#[serde(with = "hex_prefixed_upper")]
+		_3: Vec<u8>,
+	}
+
+	let ljf = Ljf { _0: 52, _1: 520, _2: *b"Love", _3: b"Jane".to_vec() };
+	let json = serde_json::to_string(&ljf).unwrap();
+
+	assert_eq!(json, r#"{"_0":"34","_1":"208","_2":"0x4c6f7665","_3":"0x4A616E65"}"#);
+	assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+	// The deserialization accepts any case and prefix, as `de_dehexify` does.
+	assert_eq!(
+		serde_json::from_str::<Ljf>(
+			r#"{"_0":"0x34","_1":"0x208","_2":"4C6F7665","_3":"4a616e65"}"#
+		)
+		.unwrap(),
+		ljf
+	);
+}
+
+/// Serialize `T` to hex if the format is human-readable, or to raw bytes otherwise.
+///
+/// JSON, TOML and YAML get a hex string, while the binary formats such as bincode, postcard and
+/// CBOR get the bytes as is through [`serde_bytes`].
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Ljf {
+/// 	#[serde(serialize_with = "array_bytes::ser_hexify_or_bytes")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::to_string(&Ljf { _0: *b"Love" }).unwrap(), r#"{"_0":"4c6f7665"}"#);
+/// ```
+pub fn ser_hexify_or_bytes<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: ?Sized + AsRef<[u8]>,
+{
+	if serializer.is_human_readable() {
+		serializer.serialize_str(&value.as_ref().hexify())
+	} else {
+		serde_bytes::serialize(value.as_ref(), serializer)
+	}
+}
+
+/// Serialize `T` to hex with `0x` prefix if the format is human-readable, or to raw bytes
+/// otherwise.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Ljf {
+/// 	#[serde(serialize_with = "array_bytes::ser_hexify_prefixed_or_bytes")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::to_string(&Ljf { _0: *b"Love" }).unwrap(), r#"{"_0":"0x4c6f7665"}"#);
+/// ```
+pub fn ser_hexify_prefixed_or_bytes<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: ?Sized + AsRef<[u8]>,
+{
+	if serializer.is_human_readable() {
+		serializer.serialize_str(&value.as_ref().hexify_prefixed())
+	} else {
+		serde_bytes::serialize(value.as_ref(), serializer)
+	}
+}
+
+/// Deserialize hex or raw bytes to `T`.
+///
+/// Both representations are accepted in any format, the hex may or may not have the `0x` prefix.
+/// A sequence of bytes is accepted too, since some formats have no dedicated bytes type.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Ljf {
+/// 	#[serde(deserialize_with = "array_bytes::de_dehexify_or_bytes")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::from_str::<Ljf>(r#"{"_0":"0x4c6f7665"}"#).unwrap(),
+/// 	Ljf { _0: *b"Love" }
+/// );
+/// assert_eq!(
+/// 	serde_json::from_str::<Ljf>(r#"{"_0":[76,111,118,101]}"#).unwrap(),
+/// 	Ljf { _0: *b"Love" }
+/// );
+/// ```
+pub fn de_dehexify_or_bytes<'de, D, T>(hex_or_bytes: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: TryFrom<Vec<u8>>,
+{
+	struct HexOrBytes;
+	impl<'de> Visitor<'de> for HexOrBytes {
+		type Value = Vec<u8>;
+
+		fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+			formatter.write_str("hex or bytes")
+		}
+
+		fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+		where
+			E: DeError,
+		{
+			<Vec<u8>>::dehexify(v).map_err(|e| E::custom(format!("{e:?}")))
+		}
+
+		fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+		where
+			E: DeError,
+		{
+			Ok(v.to_vec())
+		}
+
+		fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+		where
+			E: DeError,
+		{
+			Ok(v)
+		}
+
+		fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+		where
+			A: SeqAccess<'de>,
+		{
+			// Don't trust the size hint for the allocation.
+			let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+			while let Some(byte) = seq.next_element()? {
+				bytes.push(byte);
+			}
+
+			Ok(bytes)
+		}
+	}
+
+	let bytes = if hex_or_bytes.is_human_readable() {
+		hex_or_bytes.deserialize_any(HexOrBytes)?
+	} else {
+		hex_or_bytes.deserialize_bytes(HexOrBytes)?
+	};
+	let len = bytes.len();
+
+	T::try_from(bytes).map_err(|_| D::Error::invalid_length(len, &"the length of the target"))
+}
+#[test]
+fn hexify_or_bytes_serde_should_work() {
+	#[derive(Debug, PartialEq, Deserialize, Serialize)]
+	struct Ljf {
+		#[serde(deserialize_with = "de_dehexify_or_bytes", serialize_with = "ser_hexify_or_bytes")]
+		_0: [u8; 4],
+		#[serde(
+			deserialize_with = "de_dehexify_or_bytes",
+			serialize_with = "ser_hexify_prefixed_or_bytes"
+		)]
+		_1: Vec<u8>,
+	}
+
+	let ljf = Ljf { _0: *b"Love", _1: b"Jane Forever".to_vec() };
+
+	// Human-readable.
+	let json = serde_json::to_string(&ljf).unwrap();
+
+	assert_eq!(json, r#"{"_0":"4c6f7665","_1":"0x4a616e6520466f7265766572"}"#);
+	assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+	assert_eq!(
+		serde_json::from_str::<Ljf>(
+			r#"{"_0":[76,111,118,101],"_1":[74,97,110,101,32,70,111,114,101,118,101,114]}"#
+		)
+		.unwrap(),
+		ljf
+	);
+	assert!(serde_json::from_str::<Ljf>(r#"{"_0":"4c6f76","_1":""}"#).is_err());
+	assert!(serde_json::from_str::<Ljf>(r#"{"_0":"4c6f766g","_1":""}"#).is_err());
+	assert!(serde_json::from_str::<Ljf>(r#"{"_0":[76,111,118,256],"_1":""}"#).is_err());
+
+	// Binary, the bytes are prefixed with their varint length.
+	let bytes = postcard::to_allocvec(&ljf).unwrap();
+
+	assert_eq!(bytes, [&[4][..], b"Love", &[12], b"Jane Forever"].concat());
+	assert_eq!(postcard::from_bytes::<Ljf>(&bytes).unwrap(), ljf);
+	assert!(postcard::from_bytes::<Ljf>(&[3, b'L', b'o', b'v', 0]).is_err());
+}
+
+/// Serialize `T` to SS58 address with the network prefix.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Account {
+/// 	#[serde(serialize_with = "array_bytes::ser_ss58ify::<_, _, 42>")]
+/// 	id: [u8; 32],
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::to_string(&Account { id: [0; 32] }).unwrap(),
+/// 	r#"{"id":"5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM"}"#
+/// );
+/// ```
+pub fn ser_ss58ify<S, T, const PREFIX: u16>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: Ss58ify,
+{
+	serializer
+		.serialize_str(&value.ss58ify(PREFIX).map_err(|e| S::Error::custom(format!("{e:?}")))?)
+}
+
+/// Deserialize SS58 address with the network prefix to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Account {
+/// 	#[serde(deserialize_with = "array_bytes::de_dess58ify::<_, _, 42>")]
+/// 	id: [u8; 32],
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::from_str::<Account>(
+/// 		r#"{"id":"5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM"}"#
+/// 	)
+/// 	.unwrap(),
+/// 	Account { id: [0; 32] }
+/// );
+/// ```
+pub fn de_dess58ify<'de, D, T, const PREFIX: u16>(ss58: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Dess58ify,
+{
+	let ss58 = <&str>::deserialize(ss58)?;
+
+	T::dess58ify_with_prefix(ss58, PREFIX).map_err(|e| D::Error::custom(format!("{e:?}")))
+}
+#[test]
+fn ss58_serde_should_work() {
+	use alloc::string::ToString;
+
+	#[derive(Debug, PartialEq, Deserialize, Serialize)]
+	struct Account {
+		#[serde(
+			deserialize_with = "de_dess58ify::<_, _, 0>",
+			serialize_with = "ser_ss58ify::<_, _, 0>"
+		)]
+		id: [u8; 32],
+	}
+
+	let account = Account { id: [1; 32] };
+	let json = serde_json::to_string(&account).unwrap();
+
+	assert_eq!(serde_json::from_str::<Account>(&json).unwrap(), account);
+	assert!(
+		serde_json::from_str::<Account>(
+			r#"{"id":"5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM"}"#
+		)
+		.unwrap_err()
+		.to_string()
+		.starts_with("InvalidPrefix { prefix: 42 }")
+	);
+}
+
+/// Serialize `T` to Base64 with the standard alphabet and padding.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Ljf {
+/// 	#[serde(serialize_with = "array_bytes::ser_base64ify")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::to_string(&Ljf { _0: *b"Love" }).unwrap(), r#"{"_0":"TG92ZQ=="}"#);
+/// ```
+pub fn ser_base64ify<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: ?Sized + Base64ify,
+{
+	serializer.serialize_str(&value.base64ify())
+}
+
+/// Serialize `T` to Base64 with the standard alphabet and without padding.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Ljf {
+/// 	#[serde(serialize_with = "array_bytes::ser_base64ify_unpadded")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::to_string(&Ljf { _0: *b"Love" }).unwrap(), r#"{"_0":"TG92ZQ"}"#);
+/// ```
+pub fn ser_base64ify_unpadded<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: ?Sized + Base64ify,
+{
+	serializer.serialize_str(&value.base64ify_unpadded())
+}
+
+/// Serialize `T` to Base64 with the URL-safe alphabet and padding.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Ljf {
+/// 	#[serde(serialize_with = "array_bytes::ser_base64ify_url")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::to_string(&Ljf { _0: *b"Love" }).unwrap(), r#"{"_0":"TG92ZQ=="}"#);
+/// ```
+pub fn ser_base64ify_url<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: ?Sized + Base64ify,
+{
+	serializer.serialize_str(&value.base64ify_url())
+}
+
+/// Serialize `T` to Base64 with the URL-safe alphabet and without padding.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct Ljf {
+/// 	#[serde(serialize_with = "array_bytes::ser_base64ify_url_unpadded")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::to_string(&Ljf { _0: *b"Love" }).unwrap(), r#"{"_0":"TG92ZQ"}"#);
+/// ```
+pub fn ser_base64ify_url_unpadded<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: ?Sized + Base64ify,
+{
+	serializer.serialize_str(&value.base64ify_url_unpadded())
+}
+
+/// Deserialize Base64 with the standard alphabet and padding to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Ljf {
+/// 	#[serde(deserialize_with = "array_bytes::de_debase64ify")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::from_str::<Ljf>(r#"{"_0":"TG92ZQ=="}"#).unwrap(), Ljf { _0: *b"Love" });
+/// ```
+pub fn de_debase64ify<'de, D, T>(base64: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Debase64ify,
+{
+	let base64 = <&str>::deserialize(base64)?;
+
+	T::debase64ify(base64).map_err(|e| D::Error::custom(format!("{e:?}")))
+}
+
+/// Deserialize Base64 with the standard alphabet and without padding to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Ljf {
+/// 	#[serde(deserialize_with = "array_bytes::de_debase64ify_unpadded")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::from_str::<Ljf>(r#"{"_0":"TG92ZQ"}"#).unwrap(), Ljf { _0: *b"Love" });
+/// ```
+pub fn de_debase64ify_unpadded<'de, D, T>(base64: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Debase64ify,
+{
+	let base64 = <&str>::deserialize(base64)?;
+
+	T::debase64ify_unpadded(base64).map_err(|e| D::Error::custom(format!("{e:?}")))
+}
+
+/// Deserialize Base64 with the URL-safe alphabet and padding to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Ljf {
+/// 	#[serde(deserialize_with = "array_bytes::de_debase64ify_url")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::from_str::<Ljf>(r#"{"_0":"TG92ZQ=="}"#).unwrap(), Ljf { _0: *b"Love" });
+/// ```
+pub fn de_debase64ify_url<'de, D, T>(base64: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Debase64ify,
+{
+	let base64 = <&str>::deserialize(base64)?;
+
+	T::debase64ify_url(base64).map_err(|e| D::Error::custom(format!("{e:?}")))
+}
+
+/// Deserialize Base64 with the URL-safe alphabet and without padding to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Ljf {
+/// 	#[serde(deserialize_with = "array_bytes::de_debase64ify_url_unpadded")]
+/// 	_0: [u8; 4],
+/// }
+///
+/// assert_eq!(serde_json::from_str::<Ljf>(r#"{"_0":"TG92ZQ"}"#).unwrap(), Ljf { _0: *b"Love" });
+/// ```
+pub fn de_debase64ify_url_unpadded<'de, D, T>(base64: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Debase64ify,
+{
+	let base64 = <&str>::deserialize(base64)?;
+
+	T::debase64ify_url_unpadded(base64).map_err(|e| D::Error::custom(format!("{e:?}")))
+}
+
+#[test]
+fn base64_serde_should_work() {
+	#[derive(Debug, PartialEq, Deserialize, Serialize)]
+	struct Ljf {
+		#[serde(deserialize_with = "de_debase64ify", serialize_with = "ser_base64ify")]
+		_0: Vec<u8>,
+		#[serde(
+			deserialize_with = "de_debase64ify_unpadded",
+			serialize_with = "ser_base64ify_unpadded"
+		)]
+		_1: Vec<u8>,
+		#[serde(deserialize_with = "de_debase64ify_url", serialize_with = "ser_base64ify_url")]
+		_2: [u8; 2],
+		#[serde(
+			deserialize_with = "de_debase64ify_url_unpadded",
+			serialize_with = "ser_base64ify_url_unpadded"
+		)]
+		_3: [u8; 2],
+	}
+
+	let ljf =
+		Ljf { _0: b"Love".to_vec(), _1: b"Jane".to_vec(), _2: [0xfb, 0xff], _3: [0xfb, 0xff] };
+	let json = serde_json::to_string(&ljf).unwrap();
+
+	assert_eq!(json, r#"{"_0":"TG92ZQ==","_1":"SmFuZQ","_2":"-_8=","_3":"-_8"}"#);
+	assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+	assert!(
+		serde_json::from_str::<Ljf>(r#"{"_0":"TG92ZQ","_1":"SmFuZQ","_2":"-_8=","_3":"-_8"}"#)
+			.is_err()
+	);
+}
+
+/// Serialize and deserialize bytes with the given [`Encoding`].
+///
+/// The encodings of this crate are in [`encoding`](crate::encoding), the ones implemented by other
+/// crates work the same way.
+///
+/// # Examples
+/// ```
+/// use array_bytes::encoding::{Base58, Base64, Hex};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Ljf {
+/// 	#[serde(with = "array_bytes::as_encoded::<Hex>")]
+/// 	_0: [u8; 4],
+/// 	#[serde(with = "array_bytes::as_encoded::<Base58>")]
+/// 	_1: Vec<u8>,
+/// 	#[serde(with = "array_bytes::as_encoded::<Base64>")]
+/// 	_2: Vec<u8>,
+/// }
+///
+/// let ljf = Ljf { _0: *b"Love", _1: b"Jane".to_vec(), _2: b"Forever".to_vec() };
+/// let json = serde_json::to_string(&ljf).unwrap();
+///
+/// assert_eq!(json, r#"{"_0":"4c6f7665","_1":"2uGoy6","_2":"Rm9yZXZlcg=="}"#);
+/// assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+/// ```
+#[allow(non_camel_case_types)]
+pub struct as_encoded<E>(PhantomData<E>);
+impl<E> as_encoded<E>
+where
+	E: Encoding,
+{
+	/// Serialize `T` with the encoding.
+	pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+		T: ?Sized + AsRef<[u8]>,
+	{
+		serializer.serialize_str(&E::encode(value.as_ref()))
+	}
+
+	/// Deserialize `T` with the encoding.
+	pub fn deserialize<'de, D, T>(encoded: D) -> Result<T, D::Error>
+	where
+		D: Deserializer<'de>,
+		T: TryFrom<Vec<u8>>,
+	{
+		let encoded = <&str>::deserialize(encoded)?;
+		let bytes =
+			E::decode(encoded.as_bytes()).map_err(|e| D::Error::custom(format!("{e:?}")))?;
+		let len = bytes.len();
+
+		T::try_from(bytes).map_err(|_| D::Error::invalid_length(len, &"the length of the target"))
+	}
+}
+#[test]
+fn as_encoded_should_work() {
+	// self
+	use crate::encoding::{Base32, Hex};
+
+	#[derive(Debug, PartialEq, Deserialize, Serialize)]
+	struct Ljf {
+		#[serde(with = "as_encoded::<Hex>")]
+		_0: [u8; 4],
+		#[serde(with = "as_encoded::<Base32>")]
+		_1: Vec<u8>,
+	}
+
+	let ljf = Ljf { _0: *b"Love", _1: b"Jane".to_vec() };
+	let json = serde_json::to_string(&ljf).unwrap();
+
+	assert_eq!(json, r#"{"_0":"4c6f7665","_1":"JJQW4ZI="}"#);
+	assert_eq!(serde_json::from_str::<Ljf>(&json).unwrap(), ljf);
+	assert!(serde_json::from_str::<Ljf>(r#"{"_0":"4c6f76","_1":"JJQW4ZI="}"#).is_err());
+	assert!(serde_json::from_str::<Ljf>(r#"{"_0":"4c6f7665","_1":"JJQW4ZI"}"#).is_err());
+}
+
 #[test]
 fn serde_should_work() {
 	#[derive(Debug, PartialEq, Deserialize, Serialize)]
@@ -0,0 +1,243 @@
+// self
+use crate::{Base58ify, Debase58ify, hash, prelude::*};
+
+// The largest prefix which fits in the 14 bits of the two bytes form.
+const MAX_PREFIX: u16 = 0x3fff;
+
+/// SS58ify `Self` with the network prefix.
+///
+/// The prefixes below 64 are encoded in one byte, the others up to 16383 in two bytes. The checksum
+/// is the first 2 bytes of the Blake2b-512 of `SS58PRE`, the prefix and the payload.
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Dehexify, Error, Ss58ify};
+///
+/// let alice =
+/// 	<[u8; 32]>::dehexify("0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+/// 		.unwrap();
+///
+/// // Substrate.
+/// assert_eq!(alice.ss58ify(42), Ok("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".into()));
+/// // Polkadot.
+/// assert_eq!(alice.ss58ify(0), Ok("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5".into()));
+/// assert_eq!(alice.ss58ify(16384), Err(Error::InvalidPrefix { prefix: 16384 }));
+/// ```
+pub trait Ss58ify {
+	/// SS58ify `Self` with the network prefix.
+	fn ss58ify(&self, prefix: u16) -> Result<String>;
+}
+macro_rules! impl_ss58ify_for_arrays {
+	($($n:expr,)+) => {
+		$(
+			impl Ss58ify for [u8; $n] {
+				fn ss58ify(&self, prefix: u16) -> Result<String> {
+					ss58ify(self, prefix)
+				}
+			}
+		)+
+	};
+}
+impl_ss58ify_for_arrays! {
+	20,
+	32,
+	33,
+}
+#[test]
+fn ss58ify_should_work() {
+	let alice = [
+		0xd4, 0x35, 0x93, 0xc7, 0x15, 0xfd, 0xd3, 0x1c, 0x61, 0x14, 0x1a, 0xbd, 0x04, 0xa9, 0x9f,
+		0xd6, 0x82, 0x2c, 0x85, 0x58, 0x85, 0x4c, 0xcd, 0xe3, 0x9a, 0x56, 0x84, 0xe7, 0xa5, 0x6d,
+		0xa2, 0x7d,
+	];
+
+	assert_eq!(alice.ss58ify(2), Ok("HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F".into()));
+	assert_eq!(alice.ss58ify(63), Ok("7NPoMQbiA6trJKkjB35uk96MeJD4PGWkLQLH7k7hXEkZpiba".into()));
+	assert_eq!(alice.ss58ify(64), Ok("cEaNSpz4PxFcZ7nT1VEKrKewH67rfx6MfcM6yKojyyPz7qaqp".into()));
+	assert_eq!(alice.ss58ify(255), Ok("yGHXkYLYqxijLKKfd9Q2CB9shRVu8rPNBS53wvwGTutYg4zTg".into()));
+	assert_eq!(
+		alice.ss58ify(16383),
+		Ok("yNa8JpqfFB3q8A29rCwSgxvdU94ufJw2yKKxDgznS5m1PoFvn".into())
+	);
+	assert_eq!(alice.ss58ify(u16::MAX), Err(Error::InvalidPrefix { prefix: u16::MAX }));
+
+	let mut compressed = [2; 33];
+
+	compressed[1..].copy_from_slice(&alice);
+	assert_eq!(
+		compressed.ss58ify(42),
+		Ok("KW7itot55zZFQ4soCM2AqjCLMCq2k9cFLzD1ya4jcetmcd4f5".into())
+	);
+
+	let mut account_id20 = [0; 20];
+
+	account_id20.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+	assert_eq!(account_id20.ss58ify(42), Ok("sKDJwGgP42ifhnUdJMqgNVjwJACq3hg".into()));
+	assert_eq!(account_id20.ss58ify(1284), Ok("6vnuspKhK8QtwazhXFAVE9EHEG9L136hN".into()));
+}
+
+/// Dess58ify the given SS58 address to `Self`.
+///
+/// The checksum is verified. Besides the errors of [`Debase58ify`], a wrong checksum is reported
+/// with [`Error::MismatchedChecksum`], and a reserved or unexpected prefix is reported with
+/// [`Error::InvalidPrefix`].
+///
+/// # Examples
+/// ```
+/// use array_bytes::{Dess58ify, Error, Ss58ify};
+///
+/// let (prefix, alice) =
+/// 	<[u8; 32]>::dess58ify("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+///
+/// assert_eq!(prefix, 42);
+/// assert_eq!(alice.ss58ify(0), Ok("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5".into()));
+/// assert_eq!(
+/// 	<[u8; 32]>::dess58ify_with_prefix("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", 0),
+/// 	Err(Error::InvalidPrefix { prefix: 42 })
+/// );
+/// ```
+pub trait Dess58ify
+where
+	Self: Sized,
+{
+	/// Dess58ify `Self` and the network prefix from the SS58 address.
+	fn dess58ify<S>(ss58: S) -> Result<(u16, Self)>
+	where
+		S: AsRef<[u8]>;
+
+	/// Dess58ify `Self` from the SS58 address, which must carry the given network prefix.
+	fn dess58ify_with_prefix<S>(ss58: S, prefix: u16) -> Result<Self>
+	where
+		S: AsRef<[u8]>,
+	{
+		let (actual, this) = Self::dess58ify(ss58)?;
+
+		if actual != prefix {
+			Err(Error::InvalidPrefix { prefix: actual })?;
+		}
+
+		Ok(this)
+	}
+}
+macro_rules! impl_dess58ify_for_arrays {
+	($($n:expr,)+) => {
+		$(
+			impl Dess58ify for [u8; $n] {
+				fn dess58ify<S>(ss58: S) -> Result<(u16, Self)>
+				where
+					S: AsRef<[u8]>,
+				{
+					dess58ify(ss58.as_ref())
+				}
+			}
+		)+
+	};
+}
+impl_dess58ify_for_arrays! {
+	20,
+	32,
+	33,
+}
+#[test]
+fn dess58ify_should_work() {
+	let alice =
+		<[u8; 32]>::dess58ify("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap().1;
+
+	for prefix in [0, 2, 42, 63, 64, 255, 1284, 16383] {
+		assert_eq!(<[u8; 32]>::dess58ify(alice.ss58ify(prefix).unwrap()), Ok((prefix, alice)));
+	}
+
+	assert_eq!(
+		<[u8; 33]>::dess58ify("KW7itot55zZFQ4soCM2AqjCLMCq2k9cFLzD1ya4jcetmcd4f5").map(|(p, _)| p),
+		Ok(42)
+	);
+	assert_eq!(
+		<[u8; 20]>::dess58ify_with_prefix("6vnuspKhK8QtwazhXFAVE9EHEG9L136hN", 1284).map(|a| a[19]),
+		Ok(19)
+	);
+
+	assert_eq!(
+		<[u8; 32]>::dess58ify("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"),
+		Err(Error::MismatchedChecksum { expect: 0x1d21, actual: 0x1d22 })
+	);
+	assert_eq!(
+		<[u8; 32]>::dess58ify("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQ0"),
+		Err(Error::InvalidCharacter { character: '0', index: 47, char_index: 47 })
+	);
+	assert_eq!(
+		<[u8; 20]>::dess58ify("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+		Err(Error::MismatchedLength { expect: 20, actual: 32, unit: LengthUnit::Byte })
+	);
+	assert_eq!(
+		<[u8; 32]>::dess58ify(""),
+		Err(Error::MismatchedLength { expect: 32, actual: 0, unit: LengthUnit::Byte })
+	);
+	// The first byte is `0x80`.
+	assert_eq!(
+		<[u8; 32]>::dess58ify([0x80; 35].base58ify()),
+		Err(Error::InvalidPrefix { prefix: 0x80 })
+	);
+}
+
+fn ss58ify(payload: &[u8], prefix: u16) -> Result<String> {
+	if prefix > MAX_PREFIX {
+		Err(Error::InvalidPrefix { prefix })?;
+	}
+
+	let mut bytes = Vec::with_capacity(2 + payload.len() + 2);
+
+	if prefix < 64 {
+		bytes.push(prefix as u8);
+	} else {
+		// The lower 6 bits of the first byte hold the bits 2..8, the second byte holds the bits
+		// 0..2 in its upper 2 bits and the bits 8..14 in the rest.
+		bytes.push(0b0100_0000 | ((prefix & 0b1111_1100) >> 2) as u8);
+		bytes.push(((prefix >> 8) | ((prefix & 0b11) << 6)) as u8);
+	}
+
+	bytes.extend_from_slice(payload);
+	bytes.extend_from_slice(&checksum(&bytes));
+
+	Ok(bytes.base58ify())
+}
+
+fn dess58ify<const N: usize>(ss58: &[u8]) -> Result<(u16, [u8; N])> {
+	let bytes = <Vec<u8>>::debase58ify(ss58)?;
+	let (prefix, prefix_len) = match bytes.first() {
+		Some(&b @ 0..64) => (b as u16, 1),
+		Some(&b @ 64..128) if bytes.len() > 1 => {
+			let lower = (b << 2) | (bytes[1] >> 6);
+			let upper = bytes[1] & 0b0011_1111;
+
+			(lower as u16 | (upper as u16) << 8, 2)
+		},
+		Some(&b @ 128..) => Err(Error::InvalidPrefix { prefix: b as _ })?,
+		// Too short, leave it to the length check.
+		_ => (0, 1),
+	};
+	let actual = bytes.len().saturating_sub(prefix_len + 2);
+
+	if actual != N {
+		Err(Error::MismatchedLength { expect: N, actual, unit: LengthUnit::Byte })?;
+	}
+
+	let (body, checksum_bytes) = bytes.split_at(bytes.len() - 2);
+	let expect = u16::from_be_bytes(checksum(body));
+	let actual = u16::from_be_bytes([checksum_bytes[0], checksum_bytes[1]]);
+
+	if expect != actual {
+		Err(Error::MismatchedChecksum { expect: expect as _, actual: actual as _ })?;
+	}
+
+	let mut payload = [0; N];
+
+	payload.copy_from_slice(&body[prefix_len..]);
+
+	Ok((prefix, payload))
+}
+
+fn checksum(body: &[u8]) -> [u8; 2] {
+	let hash = hash::blake2b_512(&[b"SS58PRE", body]);
+
+	[hash[0], hash[1]]
+}